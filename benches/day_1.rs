@@ -0,0 +1,125 @@
+//! Compares the day 1 digit scanner with the trie search it replaced, on generated inputs
+//! of growing size. Run with `cargo bench --bench day_1`.
+
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use trie_rs::{Trie, TrieBuilder};
+use advent_of_code_2023::days::day_1::DigitScanner;
+
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+const FILLER: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const RUNS: usize = 3;
+
+/// Lines of letters with spelled out digits, some of them overlapping, and the odd written
+/// digit, from a fixed seed so every run sees the same input.
+fn generate_input(lines: usize) -> String {
+    let mut seed: u64 = 2023;
+    let mut next = move |bound: usize| {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (seed >> 33) as usize % bound
+    };
+
+    let mut input = String::new();
+    for _ in 0..lines {
+        for _ in 0..(20 + next(40)) {
+            match next(10) {
+                0 => input.push_str(WORDS[next(WORDS.len())]),
+                1 => input.push_str("eightwone"),
+                2 => input.push((b'0' + next(10) as u8) as char),
+                _ => input.push(FILLER[next(FILLER.len())] as char),
+            }
+        }
+        input.push('\n');
+    }
+    input
+}
+
+/// The part 2 search before the scanner: from every position, walk the prefixes of the rest
+/// of the line until one spells a digit or the trie says none can.
+mod trie_search {
+    use super::*;
+
+    pub fn create_digit_trie() -> Trie<u8> {
+        let mut builder = TrieBuilder::new();
+        for word in WORDS {
+            builder.push(word);
+        }
+        builder.build()
+    }
+
+    pub fn create_digit_map() -> HashMap<&'static str, char> {
+        WORDS.iter().zip('1'..='9').map(|(word, digit)| (*word, digit)).collect()
+    }
+
+    fn attempt_slice_translation(slice: &str, trie: &Trie<u8>, digit_map: &HashMap<&str, char>) -> Option<char> {
+        for i in 0..slice.len() {
+            let check_slice = &slice[0..=i];
+            match digit_map.get(check_slice) {
+                Some(value) => return Some(*value),
+                None => {
+                    if trie.predictive_search(check_slice).is_empty() {
+                        return None
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn get_digits_from_numerals_and_words(line: &str, trie: &Trie<u8>, digit_map: &HashMap<&str, char>) -> Vec<char> {
+        let mut list = Vec::new();
+        for (i, c) in line.char_indices() {
+            if c.is_numeric() {
+                list.push(c);
+            } else if let Some(digit) = attempt_slice_translation(&line[i..], trie, digit_map) {
+                list.push(digit);
+            }
+        }
+        list
+    }
+
+    pub fn sum(input: &str, trie: &Trie<u8>, digit_map: &HashMap<&str, char>) -> u32 {
+        input.lines().map(|line| {
+            let digits = get_digits_from_numerals_and_words(line, trie, digit_map);
+            match (digits.first(), digits.last()) {
+                (Some(first), Some(last)) => first.to_digit(10).unwrap() * 10 + last.to_digit(10).unwrap(),
+                _ => 0,
+            }
+        }).sum()
+    }
+}
+
+fn scanner_sum(scanner: &DigitScanner, input: &str) -> u32 {
+    input.lines().map(|line| {
+        let mut digits = scanner.digits(line);
+        digits.next().map(|first| first * 10 + digits.last().unwrap_or(first)).unwrap_or(0)
+    }).sum()
+}
+
+fn median<F>(mut run: F) -> (u32, Duration) where F: FnMut() -> u32 {
+    let mut answer = 0;
+    let mut samples: Vec<Duration> = (0..RUNS).map(|_| {
+        let start = Instant::now();
+        answer = black_box(run());
+        start.elapsed()
+    }).collect();
+    samples.sort();
+    (answer, samples[RUNS / 2])
+}
+
+fn main() {
+    let scanner = DigitScanner::new();
+    let trie = trie_search::create_digit_trie();
+    let digit_map = trie_search::create_digit_map();
+
+    println!("Median of {} runs", RUNS);
+    for lines in [1_000, 10_000, 50_000] {
+        let input = generate_input(lines);
+        let (expected, trie_time) = median(|| trie_search::sum(&input, &trie, &digit_map));
+        let (answer, automaton) = median(|| scanner_sum(&scanner, &input));
+        assert_eq!(answer, expected, "The scanner disagrees with the trie search on {} lines", lines);
+        println!("{:>7} lines ({:>4.1} MB): trie search {:>10.2?}, automaton {:>10.2?} ({:.0}x)",
+                 lines, input.len() as f64 / 1e6, trie_time, automaton, trie_time.as_secs_f64() / automaton.as_secs_f64());
+    }
+}
@@ -0,0 +1,115 @@
+use std::time::Duration;
+use crate::cli::RunOptions;
+use crate::days;
+use crate::days::Day;
+use crate::error::Error;
+use crate::runner::load_input;
+use crate::utils::output::format_duration;
+
+pub const DEFAULT_RUNS: usize = 10;
+
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Stats> {
+        samples.sort();
+        let middle = samples.len() / 2;
+        let median = match samples.len() {
+            0 => return None,
+            len if len % 2 == 0 => (samples[middle - 1] + samples[middle]) / 2,
+            _ => samples[middle],
+        };
+        Some(Stats { min: samples[0], median, max: samples[samples.len() - 1] })
+    }
+}
+
+#[derive(Debug)]
+pub struct BenchResult {
+    pub day: u32,
+    pub part: u32,
+    /// Parse and solve timings, or the error that stopped the part from being benchmarked.
+    pub timings: Result<(Stats, Stats), Error>,
+}
+
+fn bench_part(day: &Day, input: &str, part: u32, options: &RunOptions, runs: usize) -> Result<(Stats, Stats), Error> {
+    let mut parse_times = Vec::with_capacity(runs);
+    let mut solve_times = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        for result in (day.run)(input, &[part], options.mode) {
+            result.result?;
+            parse_times.push(result.parse_time);
+            solve_times.push(result.solve_time);
+        }
+    }
+
+    match (Stats::from_samples(parse_times), Stats::from_samples(solve_times)) {
+        (Some(parse), Some(solve)) => Ok((parse, solve)),
+        _ => Err(Error::malformed("Benchmarks need at least one run")),
+    }
+}
+
+/// Runs each selected part `runs` times, parsing the input from scratch every time.
+pub fn run(options: &RunOptions, runs: usize) -> Vec<BenchResult> {
+    let available: Vec<u32> = days::registry().iter().map(|day| day.number).collect();
+    let mut results = Vec::new();
+
+    for number in options.selected_days(&available) {
+        let input = match days::find_day(number) {
+            Some(day) => load_input(&day, options).map(|input| (day, input)),
+            None => Err(Error::UnknownDay(number)),
+        };
+
+        for part in options.parts.iter() {
+            let timings = match &input {
+                Ok((day, input)) => bench_part(day, input, *part, options, runs),
+                Err(err) => Err(err.clone()),
+            };
+            results.push(BenchResult { day: number, part: *part, timings });
+        }
+    }
+    results
+}
+
+pub fn report(results: &[BenchResult], runs: usize) {
+    println!("Timings over {} run(s) as min / median / max", runs);
+    for result in results {
+        match &result.timings {
+            Ok((parse, solve)) => {
+                println!("Day {} part {}: parse {} / {} / {}, solve {} / {} / {}", result.day, result.part,
+                         format_duration(parse.min), format_duration(parse.median), format_duration(parse.max),
+                         format_duration(solve.min), format_duration(solve.median), format_duration(solve.max));
+            }
+            Err(err) => println!("Day {} part {}: {}", result.day, result.part, err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|value| Duration::from_millis(*value)).collect()
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        assert_eq!(Stats::from_samples(millis(&[5, 1, 3])), Some(Stats {
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(3),
+            max: Duration::from_millis(5),
+        }));
+        assert_eq!(Stats::from_samples(millis(&[4, 1, 2, 8])), Some(Stats {
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(3),
+            max: Duration::from_millis(8),
+        }));
+        assert_eq!(Stats::from_samples(Vec::new()), None);
+    }
+}
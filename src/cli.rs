@@ -0,0 +1,496 @@
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use crate::bench::DEFAULT_RUNS;
+use crate::days::day_2::{Bag, MissingColors};
+use crate::days::day_3::visualize::Style;
+use crate::format::OutputFormat;
+use crate::solution::ParseMode;
+
+pub const USAGE: &str = "Usage: advent-of-code-2023 [run] [OPTIONS]
+
+Commands:
+  run         Run the selected solutions (default)
+  verify      Run the selected solutions (all by default) and compare them
+              with the accepted answers in <input dir>/answers.toml
+  bench       Run the selected solutions (all by default) repeatedly and report
+              the min, median and max parse and solve times
+  missing     List the days that have no input file
+
+Options:
+  --day <N>         Run a single day
+  --days <RANGE>    Run a range or list of days, e.g. 1-4 or 1,3
+  --all             Run every available day
+  --part <1|2>      Only run the given part
+  --input <PATH>    Read the puzzle input from PATH, or from stdin when PATH is -.
+                    Only valid when a single day is selected
+  --format <FORMAT> Output format for run and verify: text (default), json, csv
+                    or markdown
+  --runs <N>        How many times bench runs each part (default: 10)
+  --strict          Fail on the first line that can't be parsed instead of
+                    skipping it and reporting it after the answer
+  --visualize       Draw the day 3 schematic with the part numbers and gears
+                    highlighted. Colours are used when printing to a terminal
+  --color, --plain  Force colours, or markers under each row, for --visualize
+  --bag <CUBES>     List the day 2 games possible with a bag such as
+                    \"12 red, 13 green, 14 blue\". Can be given several times
+  --bags <PATH>     Read day 2 bags from PATH, one per line
+  --report          List the minimum bag and power of each day 2 game, which
+                    draw forced each colour, and whether the game fits the bag
+                    given with --bag or --bags (the puzzle bag by default).
+                    Supports every --format
+  --missing <N>     Count a colour of the bag that a game never drew as N cubes
+                    in the --report power (default: 0)
+  -h, --help        Print this message
+
+Environment:
+  AOC_INPUT_DIR     Directory holding the day_N_input.txt files (default: inputs)";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunOptions),
+    Verify(RunOptions),
+    Bench { options: RunOptions, runs: usize },
+    /// Draws the day 3 schematic. Without a style it is picked from the terminal.
+    Visualize { options: RunOptions, style: Option<Style> },
+    /// Checks which day 2 games are possible with each bag.
+    Bags { options: RunOptions, bags: Vec<BagSource> },
+    /// Reports what each day 2 game needed. At most one bag is allowed.
+    GameReport { options: RunOptions, bags: Vec<BagSource>, missing: MissingColors },
+    Missing,
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DaySelection {
+    All,
+    Days(Vec<u32>),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BagSource {
+    Cubes(Bag),
+    File(PathBuf),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunOptions {
+    pub days: DaySelection,
+    pub parts: Vec<u32>,
+    pub input: Option<InputSource>,
+    pub mode: ParseMode,
+    pub format: OutputFormat,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            days: DaySelection::All,
+            parts: vec![1, 2],
+            input: None,
+            mode: ParseMode::Lenient,
+            format: OutputFormat::Text,
+        }
+    }
+}
+
+impl RunOptions {
+    pub fn selected_days(&self, available: &[u32]) -> Vec<u32> {
+        match &self.days {
+            DaySelection::All => available.to_vec(),
+            DaySelection::Days(days) => days.clone(),
+        }
+    }
+}
+
+fn parse_day(value: &str) -> Result<u32, String> {
+    match value.trim().parse::<u32>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("'{}' is not a valid day, expected a number from 1 to 25", value)),
+    }
+}
+
+fn parse_day_range(value: &str) -> Result<RangeInclusive<u32>, String> {
+    match value.split_once('-') {
+        Some((start, end)) => {
+            let (start, end) = (parse_day(start)?, parse_day(end)?);
+            if start > end {
+                return Err(format!("Day range '{}' is backwards", value));
+            }
+            Ok(start..=end)
+        }
+        None => {
+            let day = parse_day(value)?;
+            Ok(day..=day)
+        }
+    }
+}
+
+/// Parses a day list such as `1-4`, `2,5` or `1-3,7` into a sorted list without duplicates.
+fn parse_days(value: &str) -> Result<Vec<u32>, String> {
+    let mut days = Vec::new();
+    for range in value.split(',') {
+        days.extend(parse_day_range(range)?);
+    }
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+fn parse_part(value: &str) -> Result<u32, String> {
+    match value {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("'{}' is not a valid part, expected 1 or 2", value)),
+    }
+}
+
+fn next_value<I>(flag: &str, args: &mut I) -> Result<String, String> where I: Iterator<Item=String> {
+    args.next().ok_or(format!("{} expects a value", flag))
+}
+
+fn parse_bench_options<I>(args: I) -> Result<Command, String> where I: Iterator<Item=String> {
+    let mut args: Vec<String> = args.collect();
+    let mut runs = DEFAULT_RUNS;
+
+    if let Some(index) = args.iter().position(|arg| arg == "--runs") {
+        let value = args.get(index + 1).ok_or(String::from("--runs expects a value"))?;
+        runs = match value.parse::<usize>() {
+            Ok(runs) if runs > 0 => runs,
+            _ => return Err(format!("'{}' is not a valid number of runs", value)),
+        };
+        args.drain(index..=index + 1);
+    }
+
+    let options = parse_run_options(args.into_iter(), Some(DaySelection::All))?;
+    if options.format != OutputFormat::Text {
+        return Err(String::from("bench only supports text output"));
+    }
+    Ok(Command::Bench { options, runs })
+}
+
+fn parse_visualize_options<I>(args: I) -> Result<Command, String> where I: Iterator<Item=String> {
+    let mut style = None;
+    let mut rest = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--visualize" => {}
+            "--color" => style = Some(Style::Ansi),
+            "--plain" => style = Some(Style::Plain),
+            _ => rest.push(arg),
+        }
+    }
+
+    let options = parse_run_options(rest.into_iter(), Some(DaySelection::Days(vec![3])))?;
+    if options.days != DaySelection::Days(vec![3]) {
+        return Err(String::from("--visualize is only available for day 3"));
+    }
+    if options.format != OutputFormat::Text {
+        return Err(String::from("--visualize only supports text output"));
+    }
+    Ok(Command::Visualize { options, style })
+}
+
+fn parse_bag_options<I>(mut args: I) -> Result<Command, String> where I: Iterator<Item=String> {
+    let mut bags = Vec::new();
+    let mut report = false;
+    let mut missing = None;
+    let mut rest = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--report" => report = true,
+            "--missing" => {
+                let value = next_value(&arg, &mut args)?;
+                missing = match value.parse::<u32>() {
+                    Ok(0) => Some(MissingColors::Zero),
+                    Ok(count) => Some(MissingColors::Default(count)),
+                    Err(_) => return Err(format!("--missing expects a number of cubes, got '{}'", value)),
+                };
+            }
+            "--bag" => {
+                let value = next_value(&arg, &mut args)?;
+                let bag = value.parse::<Bag>().map_err(|err| format!("'{}' is not a valid bag: {}", value, err))?;
+                bags.push(BagSource::Cubes(bag));
+            }
+            "--bags" => bags.push(BagSource::File(PathBuf::from(next_value(&arg, &mut args)?))),
+            _ => rest.push(arg),
+        }
+    }
+
+    let options = parse_run_options(rest.into_iter(), Some(DaySelection::Days(vec![2])))?;
+    if options.days != DaySelection::Days(vec![2]) {
+        return Err(String::from("--bag, --bags and --report are only available for day 2"));
+    }
+    if report {
+        return Ok(Command::GameReport { options, bags, missing: missing.unwrap_or_default() });
+    }
+    if missing.is_some() {
+        return Err(String::from("--missing is only used by --report"));
+    }
+    if options.format != OutputFormat::Text {
+        return Err(String::from("--bag and --bags only support text output"));
+    }
+    Ok(Command::Bags { options, bags })
+}
+
+/// Parses the options of `run`, which switch to a day specific mode when that day's flags are given.
+fn parse_run_command<I>(args: I) -> Result<Command, String> where I: Iterator<Item=String> {
+    let args: Vec<String> = args.collect();
+    if args.iter().any(|arg| arg == "--visualize") {
+        return parse_visualize_options(args.into_iter());
+    }
+    if args.iter().any(|arg| matches!(arg.as_str(), "--bag" | "--bags" | "--report")) {
+        return parse_bag_options(args.into_iter());
+    }
+    parse_run_options(args.into_iter(), None).map(Command::Run)
+}
+
+fn parse_run_options<I>(mut args: I, default_days: Option<DaySelection>) -> Result<RunOptions, String> where I: Iterator<Item=String> {
+    let mut days: Option<DaySelection> = None;
+    let mut parts = vec![1, 2];
+    let mut input = None;
+    let mut mode = ParseMode::Lenient;
+    let mut format = OutputFormat::Text;
+
+    while let Some(arg) = args.next() {
+        let selection = match arg.as_str() {
+            "--day" => DaySelection::Days(vec![parse_day(&next_value(&arg, &mut args)?)?]),
+            "--days" => DaySelection::Days(parse_days(&next_value(&arg, &mut args)?)?),
+            "--all" => DaySelection::All,
+            "--part" => {
+                parts = vec![parse_part(&next_value(&arg, &mut args)?)?];
+                continue;
+            }
+            "--input" => {
+                input = match next_value(&arg, &mut args)?.as_str() {
+                    "-" => Some(InputSource::Stdin),
+                    path => Some(InputSource::File(PathBuf::from(path))),
+                };
+                continue;
+            }
+            "--format" => {
+                let value = next_value(&arg, &mut args)?;
+                format = OutputFormat::parse(&value).ok_or(format!("Unknown format '{}', expected text, json, csv or markdown", value))?;
+                continue;
+            }
+            "--strict" => {
+                mode = ParseMode::Strict;
+                continue;
+            }
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        };
+
+        if days.is_some() {
+            return Err(String::from("Only one of --day, --days or --all may be given"));
+        }
+        days = Some(selection);
+    }
+
+    let days = days.or(default_days);
+    let single_day = matches!(&days, Some(DaySelection::Days(days)) if days.len() == 1);
+    if input.is_some() && !single_day {
+        return Err(String::from("--input can only be used when a single day is selected"));
+    }
+
+    match days {
+        Some(days) => Ok(RunOptions { days, parts, input, mode, format }),
+        None => Err(String::from("No days selected, pass --day, --days or --all")),
+    }
+}
+
+/// Parses the program arguments, excluding the binary name.
+/// Running without any arguments behaves like `run --all`.
+pub fn parse_args<I>(args: I) -> Result<Command, String> where I: IntoIterator<Item=String> {
+    let args: Vec<String> = args.into_iter().collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        return Ok(Command::Help);
+    }
+
+    let mut args = args.into_iter().peekable();
+    match args.peek().map(|arg| arg.as_str()) {
+        None => Ok(Command::Run(RunOptions::default())),
+        Some("run") => {
+            args.next();
+            parse_run_command(args)
+        }
+        Some("verify") => {
+            args.next();
+            parse_run_options(args, Some(DaySelection::All)).map(Command::Verify)
+        }
+        Some("bench") => {
+            args.next();
+            parse_bench_options(args)
+        }
+        Some("missing") => match args.nth(1) {
+            None => Ok(Command::Missing),
+            Some(arg) => Err(format!("Unknown argument '{}'", arg)),
+        },
+        Some("help") => Ok(Command::Help),
+        Some(arg) if arg.starts_with('-') => parse_run_command(args),
+        Some(arg) => Err(format!("Unknown command '{}'", arg)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        parse_args(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_single_day_and_part() {
+        assert_eq!(parse("run --day 3 --part 2"), Ok(Command::Run(RunOptions {
+            days: DaySelection::Days(vec![3]),
+            parts: vec![2],
+            ..RunOptions::default()
+        })));
+    }
+
+    #[test]
+    fn test_parse_day_ranges() {
+        assert_eq!(parse_days("1-4"), Ok(vec![1, 2, 3, 4]));
+        assert_eq!(parse_days("4,1-2,2"), Ok(vec![1, 2, 4]));
+        assert!(parse_days("4-1").is_err());
+        assert!(parse_days("0-3").is_err());
+        assert!(parse_days("one").is_err());
+    }
+
+    #[test]
+    fn test_parse_all_and_defaults() {
+        let all = Ok(Command::Run(RunOptions::default()));
+        assert_eq!(parse(""), all);
+        assert_eq!(parse("run --all"), all);
+        assert_eq!(parse("--all"), all);
+        assert_eq!(parse("missing"), Ok(Command::Missing));
+        assert_eq!(parse("run --day 1 --help"), Ok(Command::Help));
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(parse("verify"), Ok(Command::Verify(RunOptions::default())));
+        assert_eq!(parse("verify --day 3 --strict"), Ok(Command::Verify(RunOptions {
+            days: DaySelection::Days(vec![3]),
+            mode: ParseMode::Strict,
+            ..RunOptions::default()
+        })));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse("run --day 1 --input -"), Ok(Command::Run(RunOptions {
+            days: DaySelection::Days(vec![1]),
+            parts: vec![1, 2],
+            input: Some(InputSource::Stdin),
+            ..RunOptions::default()
+        })));
+        assert_eq!(parse("run --input fixtures/day_2.txt --days 2-2 --part 1 --strict"), Ok(Command::Run(RunOptions {
+            days: DaySelection::Days(vec![2]),
+            parts: vec![1],
+            input: Some(InputSource::File(PathBuf::from("fixtures/day_2.txt"))),
+            mode: ParseMode::Strict,
+            ..RunOptions::default()
+        })));
+        assert!(parse("run --all --input -").is_err());
+        assert!(parse("run --days 1-2 --input -").is_err());
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(parse("run --all --format json"), Ok(Command::Run(RunOptions { format: OutputFormat::Json, ..RunOptions::default() })));
+        assert_eq!(parse("verify --format md"), Ok(Command::Verify(RunOptions { format: OutputFormat::Markdown, ..RunOptions::default() })));
+        assert!(parse("run --all --format yaml").is_err());
+        assert!(parse("bench --format csv").is_err());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(parse("bench --day 4 --runs 50 --part 2"), Ok(Command::Bench {
+            options: RunOptions { days: DaySelection::Days(vec![4]), parts: vec![2], ..RunOptions::default() },
+            runs: 50,
+        }));
+        assert_eq!(parse("bench"), Ok(Command::Bench {
+            options: RunOptions::default(),
+            runs: DEFAULT_RUNS,
+        }));
+        assert!(parse("bench --runs 0").is_err());
+        assert!(parse("bench --runs").is_err());
+        assert!(parse("run --all --runs 5").is_err());
+    }
+
+    #[test]
+    fn test_parse_visualize() {
+        let day_3 = RunOptions { days: DaySelection::Days(vec![3]), ..RunOptions::default() };
+        assert_eq!(parse("run --visualize"), Ok(Command::Visualize { options: day_3, style: None }));
+        assert_eq!(parse("--day 3 --visualize --plain --input -"), Ok(Command::Visualize {
+            options: RunOptions { days: DaySelection::Days(vec![3]), input: Some(InputSource::Stdin), ..RunOptions::default() },
+            style: Some(Style::Plain),
+        }));
+        assert_eq!(parse("run --visualize --input inputs/day_3_input.txt"), Ok(Command::Visualize {
+            options: RunOptions { days: DaySelection::Days(vec![3]), input: Some(InputSource::File(PathBuf::from("inputs/day_3_input.txt"))), ..RunOptions::default() },
+            style: None,
+        }));
+        assert!(matches!(parse("run --visualize --color"), Ok(Command::Visualize { style: Some(Style::Ansi), .. })));
+        assert!(parse("run --day 4 --visualize").is_err());
+        assert!(parse("run --visualize --format json").is_err());
+        assert!(parse("run --day 3 --plain").is_err());
+        assert!(parse("verify --visualize").is_err());
+    }
+
+    #[test]
+    fn test_parse_bags() {
+        let args = ["run", "--bag", "12 red, 13 green, 14 blue", "--bags", "bags.txt", "--bag", "1 red"].map(String::from);
+        assert_eq!(parse_args(args), Ok(Command::Bags {
+            options: RunOptions { days: DaySelection::Days(vec![2]), ..RunOptions::default() },
+            bags: vec![
+                BagSource::Cubes(Bag::default()),
+                BagSource::File(PathBuf::from("bags.txt")),
+                BagSource::Cubes("1 red".parse().unwrap()),
+            ],
+        }));
+        assert!(matches!(parse("--day 2 --strict --bags bags.txt"), Ok(Command::Bags { options: RunOptions { mode: ParseMode::Strict, .. }, .. })));
+        assert_eq!(parse("--bags x --input -"), Ok(Command::Bags {
+            options: RunOptions { days: DaySelection::Days(vec![2]), input: Some(InputSource::Stdin), ..RunOptions::default() },
+            bags: vec![BagSource::File(PathBuf::from("x"))],
+        }));
+        assert!(matches!(parse("--report --input -"), Ok(Command::GameReport { options: RunOptions { input: Some(InputSource::Stdin), .. }, .. })));
+        assert!(parse("run --bags").is_err());
+        assert!(parse("run --bag red").is_err());
+        assert!(parse("run --day 3 --bags bags.txt").is_err());
+        assert!(parse("verify --bags bags.txt").is_err());
+    }
+
+    #[test]
+    fn test_parse_game_report() {
+        assert_eq!(parse("--report --format csv"), Ok(Command::GameReport {
+            options: RunOptions { days: DaySelection::Days(vec![2]), format: OutputFormat::Csv, ..RunOptions::default() },
+            bags: vec![],
+            missing: MissingColors::Zero,
+        }));
+        assert!(matches!(parse("--report --missing 1"), Ok(Command::GameReport { missing: MissingColors::Default(1), .. })));
+        assert!(matches!(parse("--report --missing 4294967295"), Ok(Command::GameReport { missing: MissingColors::Default(u32::MAX), .. })));
+        assert!(parse("--report --missing 4294967296").is_err());
+        assert!(parse("--report --missing none").is_err());
+        assert!(parse("--bags bags.txt --missing 1").is_err());
+        let args = ["run", "--day", "2", "--report", "--bag", "1 red"].map(String::from);
+        assert!(matches!(parse_args(args), Ok(Command::GameReport { bags, .. }) if bags == vec![BagSource::Cubes("1 red".parse().unwrap())]));
+        assert!(parse("run --day 1 --report").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("run").is_err());
+        assert!(parse("run --day").is_err());
+        assert!(parse("run --day 1 --all").is_err());
+        assert!(parse("run --all --part 3").is_err());
+        assert!(parse("run --verbose").is_err());
+        assert!(parse("solve").is_err());
+        assert!(parse("missing --day 1").is_err());
+    }
+}
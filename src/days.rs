@@ -0,0 +1,38 @@
+use std::path::{Path, PathBuf};
+use crate::solution::{run_parts, ParseMode, PartResult};
+
+pub struct Day {
+    pub number: u32,
+    pub run: fn(&str, &[u32], ParseMode) -> Vec<PartResult>,
+}
+
+impl Day {
+    pub fn input_path(&self, input_dir: &Path) -> PathBuf {
+        input_dir.join(format!("day_{}_input.txt", self.number))
+    }
+}
+
+/// Declares each day module and adds its `Solution` to the registry, so a new day
+/// only needs a file in `src/days/` and a line here.
+macro_rules! register_days {
+    ($($number:literal => $module:ident::$solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub fn registry() -> Vec<Day> {
+            vec![
+                $(Day { number: $number, run: run_parts::<$module::$solution> },)*
+            ]
+        }
+    };
+}
+
+register_days! {
+    1 => day_1::Day1,
+    2 => day_2::Day2,
+    3 => day_3::Day3,
+    4 => day_4::Day4,
+}
+
+pub fn find_day(number: u32) -> Option<Day> {
+    registry().into_iter().find(|day| day.number == number)
+}
@@ -0,0 +1,211 @@
+use std::collections::VecDeque;
+use crate::error::Error;
+use crate::solution::{Context, Solution};
+use crate::utils::file::aggregate_lines;
+
+
+/**
+  Part 1
+**/
+fn convert_chars_to_number(chars: (char, char)) -> Option<i32> {
+    let (first_digit, last_digit) = chars;
+    let formatted_number = format!("{}{}", first_digit, last_digit);
+    formatted_number.parse::<i32>().ok()
+}
+
+fn get_number_from_line_part_1(line: &str) ->  Option<i32> {
+    let first_digit_search =  line.chars().find(|i | i.is_numeric());
+    let last_digit_search = line.chars().rfind(|i| i.is_numeric());
+
+    match (first_digit_search, last_digit_search) {
+        (Some(first_digit), Some(last_digit)) => {
+            convert_chars_to_number((first_digit, last_digit))
+        }
+        _ => {
+            None
+        }
+    }
+}
+
+fn add_calibration_value(total: i32, value: i32) -> Result<i32, Error> {
+    total.checked_add(value).ok_or(Error::overflow("The calibration values are too large to add up"))
+}
+
+fn part_1_solution(input: &str, context: &mut Context) -> Result<i32, Error> {
+    aggregate_lines(input, context, |agg, cur| {
+        if let Some(num_from_line) = get_number_from_line_part_1(cur) {
+             return add_calibration_value(*agg, num_from_line)
+         }
+        Err(Error::malformed("No digits found in line"))
+    }, 0)
+}
+
+/**
+  Part 2
+**/
+const DIGIT_WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+const NO_STATE: u8 = u8::MAX;
+
+/// Finds every digit in a line, written or spelled out, in a single pass. Overlapping words
+/// are all found, so `eightwo` gives 8 then 2.
+///
+/// This is an Aho-Corasick automaton over the spelled out digits. The failure links are
+/// folded into the transition table when it is built, so scanning costs one lookup per byte.
+pub struct DigitScanner {
+    /// The next state for each state and byte. State 0 is the start.
+    transitions: Vec<[u8; 256]>,
+    /// The digit spelled out by the word that ends at each state, if any.
+    outputs: Vec<Option<u32>>,
+}
+
+impl DigitScanner {
+    pub fn new() -> DigitScanner {
+        let mut transitions = vec![[NO_STATE; 256]];
+        let mut outputs = vec![None];
+        for (index, word) in DIGIT_WORDS.iter().enumerate() {
+            let mut state = 0;
+            for byte in word.bytes() {
+                if transitions[state][byte as usize] == NO_STATE {
+                    transitions[state][byte as usize] = transitions.len() as u8;
+                    transitions.push([NO_STATE; 256]);
+                    outputs.push(None);
+                }
+                state = transitions[state][byte as usize] as usize;
+            }
+            outputs[state] = Some(index as u32 + 1);
+        }
+        debug_assert!(transitions.len() < NO_STATE as usize);
+
+        // Breadth first, so the failure link of a state is complete before its children use it
+        let mut failure = vec![0; transitions.len()];
+        let mut queue = VecDeque::from([0]);
+        while let Some(state) = queue.pop_front() {
+            let fallbacks = if state == 0 { [0; 256] } else { transitions[failure[state]] };
+            for (byte, fallback) in fallbacks.into_iter().enumerate() {
+                match transitions[state][byte] {
+                    NO_STATE => transitions[state][byte] = fallback,
+                    next => {
+                        let next = next as usize;
+                        failure[next] = fallback as usize;
+                        // No digit word ends with another one, so a state never has two outputs
+                        debug_assert!(outputs[next].is_none() || outputs[failure[next]].is_none());
+                        outputs[next] = outputs[next].or(outputs[failure[next]]);
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        DigitScanner { transitions, outputs }
+    }
+
+    /// The digits of the line in the order they end.
+    pub fn digits<'a>(&'a self, line: &'a str) -> impl Iterator<Item = u32> + 'a {
+        let mut state = 0;
+        line.bytes().filter_map(move |byte| {
+            if byte.is_ascii_digit() {
+                state = 0;
+                return Some((byte - b'0') as u32);
+            }
+            state = self.transitions[state][byte as usize] as usize;
+            self.outputs[state]
+        })
+    }
+}
+
+impl Default for DigitScanner {
+    fn default() -> Self {
+        DigitScanner::new()
+    }
+}
+
+fn get_number_from_line_part_2(line: &str, scanner: &DigitScanner) -> Option<i32> {
+    let mut digits = scanner.digits(line);
+    let first_digit = digits.next()?;
+    let last_digit = digits.last().unwrap_or(first_digit);
+    Some((first_digit * 10 + last_digit) as i32)
+}
+
+fn part_2_solution(input: &str, context: &mut Context) -> Result<i32, Error> {
+    let scanner = DigitScanner::new();
+
+    aggregate_lines(input, context, |agg, line| {
+        if let Some(number) = get_number_from_line_part_2(line, &scanner) {
+            return add_calibration_value(*agg, number)
+        }
+        Err(Error::malformed("No digits or spelled out digits found in line"))
+    }, 0)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = String;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str, _context: &mut Context) -> Result<String, Error> {
+        Ok(input.to_string())
+    }
+
+    fn part_1(input: &String, context: &mut Context) -> Result<i32, Error> {
+        part_1_solution(input, context)
+    }
+
+    fn part_2(input: &String, context: &mut Context) -> Result<i32, Error> {
+        part_2_solution(input, context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{run_parts, Answer, ParseMode};
+
+    #[test]
+    fn test_solution_on_example() {
+        let part_1_example = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+        assert_eq!(run_parts::<Day1>(part_1_example, &[1], ParseMode::Strict)[0].result, Ok(Answer::Number(142)));
+
+        let part_2_example = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen";
+        assert_eq!(run_parts::<Day1>(part_2_example, &[2], ParseMode::Strict)[0].result, Ok(Answer::Number(281)));
+    }
+
+    #[test]
+    fn test_scanner_finds_overlapping_words() {
+        let scanner = DigitScanner::new();
+        let digits = |line| scanner.digits(line).collect::<Vec<u32>>();
+        assert_eq!(digits("eightwo"), vec![8, 2]);
+        assert_eq!(digits("twoneight"), vec![2, 1, 8]);
+        assert_eq!(digits("sevenine"), vec![7, 9]);
+        assert_eq!(digits("oneight3fiveight"), vec![1, 8, 3, 5, 8]);
+        assert_eq!(digits("ninine0"), vec![9, 0]);
+        assert_eq!(digits("fonfoufour"), vec![4]);
+        assert_eq!(digits("thre3e"), vec![3]);
+        assert_eq!(digits("xyz"), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_scanner_skips_other_characters() {
+        let scanner = DigitScanner::new();
+        assert_eq!(scanner.digits("ünë1éight").collect::<Vec<u32>>(), vec![1]);
+        assert_eq!(get_number_from_line_part_2("ñtwoñ", &scanner), Some(22));
+        assert_eq!(get_number_from_line_part_2("abc", &scanner), None);
+    }
+
+    #[test]
+    fn test_calibration_values_that_overflow() {
+        assert_eq!(add_calibration_value(1, 99), Ok(100));
+        assert_eq!(add_calibration_value(i32::MAX - 98, 99), Err(Error::overflow("The calibration values are too large to add up")));
+    }
+
+    #[test]
+    fn test_lines_without_digits() {
+        let input = "1abc2\nabcdef\ntreb7uchet";
+
+        let results = run_parts::<Day1>(input, &[1], ParseMode::Lenient);
+        assert_eq!(results[0].result, Ok(Answer::Number(89)));
+        assert_eq!(results[0].skipped, vec![Error::malformed("No digits found in line").at_line(2)]);
+        assert_eq!(run_parts::<Day1>(input, &[1], ParseMode::Strict)[0].result, Err(Error::malformed("No digits found in line").at_line(2)));
+    }
+}
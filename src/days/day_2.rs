@@ -0,0 +1,488 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+use crate::error::Error;
+use crate::solution::{Context, Solution};
+
+/// The cubes shown in one handful, counted by colour.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Draw {
+    pub cubes: BTreeMap<String, u32>,
+}
+
+/// One line of the input, e.g. `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<Draw>,
+}
+
+/// Parses a cube such as `3 blue`. `line` is the text `cube` was sliced from, so errors
+/// can give the cube's column.
+fn parse_cube<'a>(line: &str, cube: &'a str) -> Result<(&'a str, u32), Error> {
+    match cube.split_once(' ') {
+        Some((count, color)) if !color.is_empty() && !color.contains(' ') => {
+            match count.parse::<u32>() {
+                Ok(count) => Ok((color, count)),
+                Err(err) => Err(Error::parse(count, err).at_token(line, count)),
+            }
+        }
+        _ => Err(Error::malformed(format!("Could not parse cube '{}', expected '<count> <color>'", cube)).at_token(line, cube)),
+    }
+}
+
+fn parse_draw(line: &str, draw: &str) -> Result<Draw, Error> {
+    let mut cubes = BTreeMap::new();
+    for cube in draw.split(", ") {
+        let (color, count) = parse_cube(line, cube)?;
+        *cubes.entry(color.to_string()).or_insert(0) += count;
+    }
+    Ok(Draw { cubes })
+}
+
+impl FromStr for Draw {
+    type Err = Error;
+
+    fn from_str(draw: &str) -> Result<Draw, Error> {
+        parse_draw(draw, draw)
+    }
+}
+
+impl FromStr for Game {
+    type Err = Error;
+
+    /// Parses a single line. Errors name the token that could not be read and its column.
+    fn from_str(line: &str) -> Result<Game, Error> {
+        let Some((game, draws)) = line.split_once(": ") else {
+            return Err(Error::malformed("Line was not formatted to the specification of the problem input"));
+        };
+        let Some(game_id) = game.split(' ').next_back() else {
+            return Err(Error::malformed("Game identifier was not formatted properly"));
+        };
+        let id = game_id.parse::<u32>().map_err(|err| Error::parse(game_id, err).at_token(line, game_id))?;
+
+        let draws = draws.split("; ").map(|draw| parse_draw(line, draw)).collect::<Result<Vec<Draw>, Error>>()?;
+        Ok(Game { id, draws })
+    }
+}
+
+impl Game {
+    /// Every cube shown in the game, across all of its draws.
+    pub fn cubes(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.draws.iter().flat_map(|draw| draw.cubes.iter().map(|(color, count)| (color.as_str(), *count)))
+    }
+}
+
+/// Parses every game, handing the lines that can't be parsed to the context.
+pub fn load_games(input: &str, context: &mut Context) -> Result<Vec<Game>, Error> {
+    let mut games = Vec::new();
+    for (line_index, line) in input.lines().enumerate() {
+        match line.parse::<Game>() {
+            Ok(game) => games.push(game),
+            Err(err) => context.skip(err.at_line(line_index + 1))?,
+        }
+    }
+    Ok(games)
+}
+
+/// The cubes a game is checked against, written like a draw: `12 red, 13 green, 14 blue`.
+/// The default is the bag from the puzzle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bag {
+    pub cubes: BTreeMap<String, u32>,
+}
+
+impl Default for Bag {
+    fn default() -> Self {
+        Bag { cubes: BTreeMap::from([
+            (String::from("red"), 12),
+            (String::from("green"), 13),
+            (String::from("blue"), 14),
+        ]) }
+    }
+}
+
+impl FromStr for Bag {
+    type Err = Error;
+
+    /// Unlike a draw, a bag can't name a colour twice, since that is most likely a typo.
+    fn from_str(text: &str) -> Result<Bag, Error> {
+        let mut cubes = BTreeMap::new();
+        for cube in text.split(", ") {
+            let (color, count) = parse_cube(text, cube)?;
+            if cubes.insert(color.to_string(), count).is_some() {
+                return Err(Error::malformed(format!("The bag has more than one count for '{}'", color)).at_token(text, cube));
+            }
+        }
+        Ok(Bag { cubes })
+    }
+}
+
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes: Vec<String> = self.cubes.iter().map(|(color, count)| format!("{} {}", count, color)).collect();
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+impl Bag {
+    /// Whether every draw in the game could have come out of this bag. Colours the bag
+    /// doesn't hold can't be drawn at all.
+    pub fn allows(&self, game: &Game) -> bool {
+        game.cubes().all(|(color, count)| count <= self.cubes.get(color).copied().unwrap_or(0))
+    }
+
+    pub fn possible_games<'a>(&self, games: &'a [Game]) -> Vec<&'a Game> {
+        games.iter().filter(|game| self.allows(game)).collect()
+    }
+}
+
+/// Reads one bag per line. Blank lines and anything after a `#` are ignored.
+pub fn parse_bags(text: &str) -> Result<Vec<Bag>, Error> {
+    let mut bags = Vec::new();
+    for (line_index, line) in text.lines().enumerate() {
+        let cubes = line.split('#').next().unwrap_or("").trim();
+        if !cubes.is_empty() {
+            bags.push(cubes.parse::<Bag>().map_err(|err| err.at_line(line_index + 1))?);
+        }
+    }
+    Ok(bags)
+}
+
+/// What a game needed, and whether a bag could have held it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameReport {
+    pub id: u32,
+    /// The fewest cubes of each colour the game could have been played with.
+    pub minimum: Bag,
+    /// For each colour, the first draw (counting from 1) that showed its minimum.
+    pub forced_by: BTreeMap<String, usize>,
+    pub power: u64,
+    pub fits: bool,
+}
+
+impl Game {
+    pub fn report(&self, bag: &Bag, missing: MissingColors) -> Result<GameReport, Error> {
+        let mut minimum: BTreeMap<String, u32> = BTreeMap::new();
+        let mut forced_by = BTreeMap::new();
+        for (draw_index, draw) in self.draws.iter().enumerate() {
+            for (color, count) in draw.cubes.iter() {
+                if minimum.get(color).is_none_or(|min| count > min) {
+                    minimum.insert(color.clone(), *count);
+                    forced_by.insert(color.clone(), draw_index + 1);
+                }
+            }
+        }
+        Ok(GameReport {
+            id: self.id,
+            power: power(self, bag, missing)?,
+            minimum: Bag { cubes: minimum },
+            forced_by,
+            fits: bag.allows(self),
+        })
+    }
+}
+
+/// Adds up the ids of the games in `u64`, so ids up to `u32::MAX` don't overflow.
+pub fn sum_ids(games: &[&Game]) -> Result<u64, Error> {
+    games.iter().try_fold(0_u64, |total, game| {
+        total.checked_add(game.id as u64).ok_or(Error::overflow("The game ids are too large to add up"))
+    })
+}
+
+fn part_1_solution(games: &[Game], bag: &Bag) -> Result<u64, Error> {
+    sum_ids(&bag.possible_games(games))
+}
+
+fn find_minimum_required_for_game(game: &Game) -> HashMap<&str, u32> {
+    let mut min_needed_per_color: HashMap<&str, u32> = HashMap::new();
+    for (color, current_num_cubes) in game.cubes() {
+        let min_needed = min_needed_per_color.entry(color).or_insert(0);
+        if *min_needed < current_num_cubes {
+            *min_needed = current_num_cubes;
+        }
+    }
+    min_needed_per_color
+}
+
+/// How the power of a game counts a colour of the bag that the game never drew.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MissingColors {
+    /// The game needs none of that colour, which makes its power zero.
+    #[default]
+    Zero,
+    /// The colour counts as this many cubes instead.
+    Default(u32),
+}
+
+impl MissingColors {
+    fn count(&self) -> u32 {
+        match self {
+            MissingColors::Zero => 0,
+            MissingColors::Default(count) => *count,
+        }
+    }
+}
+
+/// Multiplies the fewest cubes of each colour the game needed. Every colour of the bag is
+/// part of the product, along with any other colour the game drew. Powers are worked out
+/// in `u64`, so three colours of up to 100,000 cubes still fit.
+pub fn power(game: &Game, bag: &Bag, missing: MissingColors) -> Result<u64, Error> {
+    let minimum = find_minimum_required_for_game(game);
+    let absent = bag.cubes.keys().filter(|color| !minimum.contains_key(color.as_str())).count();
+    (missing.count() as u64).checked_pow(absent as u32)
+        .and_then(|product| minimum.values().try_fold(product, |product, count| product.checked_mul(*count as u64)))
+        .ok_or(Error::overflow(format!("The power of game {} is too large", game.id)))
+}
+
+fn part_2_solution(games: &[Game], bag: &Bag, missing: MissingColors) -> Result<u64, Error> {
+    games.iter().try_fold(0_u64, |total, game| {
+        total.checked_add(power(game, bag, missing)?).ok_or(Error::overflow("The powers are too large to add up"))
+    })
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str, context: &mut Context) -> Result<Vec<Game>, Error> {
+        load_games(input, context)
+    }
+
+    fn part_1(games: &Vec<Game>, _context: &mut Context) -> Result<u64, Error> {
+        part_1_solution(games, &Bag::default())
+    }
+
+    fn part_2(games: &Vec<Game>, _context: &mut Context) -> Result<u64, Error> {
+        part_2_solution(games, &Bag::default(), MissingColors::default())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{run_parts, Answer, ParseMode};
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    fn game(line: &str) -> Game {
+        line.parse().unwrap()
+    }
+
+    fn draw(cubes: &[(&str, u32)]) -> Draw {
+        Draw { cubes: cubes.iter().map(|(color, count)| (color.to_string(), *count)).collect() }
+    }
+
+    #[test]
+    fn test_parse_game() {
+        assert_eq!(game("game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"), Game {
+            id: 1,
+            draws: vec![
+                draw(&[("blue", 3), ("red", 4)]),
+                draw(&[("red", 1), ("green", 2), ("blue", 6)]),
+                draw(&[("green", 2)]),
+            ],
+        });
+
+        let bad_id = "Game 1a: 3 blue".parse::<Game>();
+        assert_eq!(bad_id.unwrap_err().to_string(), "column 6: could not parse '1a' as a number (invalid digit found in string)");
+    }
+
+    #[test]
+    fn test_parse_game_errors() {
+        let error = |line: &str| line.parse::<Game>().unwrap_err().to_string();
+
+        assert_eq!(error("Game 4 1 blue"), "Line was not formatted to the specification of the problem input");
+        assert_eq!(error("Game 2: 3 blue; x red"), "column 17: could not parse 'x' as a number (invalid digit found in string)");
+        assert_eq!(error("Game 2: 3 blue, 4red"), "column 17: Could not parse cube '4red', expected '<count> <color>'");
+        assert_eq!(error("Game 2: 3 blue; "), "column 17: Could not parse cube '', expected '<count> <color>'");
+    }
+
+    #[test]
+    fn test_parse_cube() {
+        let cube = parse_cube("2 green", "2 green");
+        assert!(cube.is_ok());
+        assert_eq!(cube.unwrap(), ("green", 2));
+    }
+
+    #[test]
+    fn test_parse_draw() {
+        assert_eq!("1 red, 2 green, 6 blue".parse::<Draw>(), Ok(draw(&[("red", 1), ("green", 2), ("blue", 6)])));
+        assert_eq!("1 red, 2 red".parse::<Draw>(), Ok(draw(&[("red", 3)])));
+        assert_eq!("1 red, two red".parse::<Draw>().unwrap_err().to_string(), "column 8: could not parse 'two' as a number (invalid digit found in string)");
+    }
+
+    #[test]
+    fn test_bag_allows_game() {
+        let bag = Bag::default();
+
+        // Verify valid result
+        assert!(bag.allows(&game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green")));
+        assert!(!bag.allows(&game("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red")));
+
+        // A colour the bag doesn't have can't be drawn
+        assert!(!bag.allows(&game("Game 2: 3 purple")));
+        let purple: Bag = "3 purple, 1 red".parse().unwrap();
+        assert!(purple.allows(&game("Game 2: 3 purple; 1 red")));
+        assert!(!purple.allows(&game("Game 2: 3 purple, 1 blue")));
+    }
+
+    #[test]
+    fn test_parse_bags() {
+        assert_eq!(Bag::default().to_string(), "14 blue, 13 green, 12 red");
+        assert_eq!("12 red, 13 green, 14 blue".parse::<Bag>(), Ok(Bag::default()));
+
+        let bags = parse_bags("# the puzzle bag\n12 red, 13 green, 14 blue\n\n5 red, 5 green  # small\n").unwrap();
+        assert_eq!(bags, vec![Bag::default(), "5 red, 5 green".parse().unwrap()]);
+
+        let error = "12 red, 13 green, 12 red".parse::<Bag>().unwrap_err();
+        assert_eq!(error, Error::malformed("The bag has more than one count for 'red'").at_column(19));
+        assert_eq!(parse_bags("12 red\n1 blue, 1 blue").unwrap_err().to_string(), "line 2, column 9: The bag has more than one count for 'blue'");
+
+        let error = parse_bags("12 red\nmany blue").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: could not parse 'many' as a number (invalid digit found in string)");
+    }
+
+    #[test]
+    fn test_possible_games_for_several_bags() {
+        let games = load_games(EXAMPLE, &mut Context::default()).unwrap();
+        let ids = |bag: &str| -> Vec<u32> { bag.parse::<Bag>().unwrap().possible_games(&games).iter().map(|game| game.id).collect() };
+
+        assert_eq!(ids("12 red, 13 green, 14 blue"), vec![1, 2, 5]);
+        assert_eq!(ids("20 red, 13 green, 15 blue"), vec![1, 2, 3, 4, 5]);
+        assert_eq!(ids("4 red, 3 green, 6 blue"), vec![1, 2]);
+        assert_eq!(ids("100 red, 100 green"), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_get_minimum_required_for_game_1() {
+        let game = game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green");
+        let min_cubes_for_game = find_minimum_required_for_game(&game);
+        let red_count = min_cubes_for_game.get("red");
+        let green_count = min_cubes_for_game.get("green");
+        let blue_count = min_cubes_for_game.get("blue");
+
+        assert_eq!(min_cubes_for_game.len(), 3);
+
+        assert!(red_count.is_some());
+        assert_eq!(*red_count.unwrap(), 4);
+
+        assert!(green_count.is_some());
+        assert_eq!(*green_count.unwrap(), 2);
+
+        assert!(blue_count.is_some());
+        assert_eq!(*blue_count.unwrap(), 6);
+    }
+
+    #[test]
+    fn test_get_minimum_required_for_game_2() {
+        let game = game("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red");
+        let min_cubes_for_game = find_minimum_required_for_game(&game);
+        let red_count = min_cubes_for_game.get("red");
+        let green_count = min_cubes_for_game.get("green");
+        let blue_count = min_cubes_for_game.get("blue");
+
+        assert_eq!(min_cubes_for_game.len(), 3);
+
+        assert!(red_count.is_some());
+        assert_eq!(*red_count.unwrap(), 20);
+
+        assert!(green_count.is_some());
+        assert_eq!(*green_count.unwrap(), 13);
+
+        assert!(blue_count.is_some());
+        assert_eq!(*blue_count.unwrap(), 6);
+    }
+
+    #[test]
+    fn test_power_of_single_color_games() {
+        let bag = Bag::default();
+        let single = game("Game 1: 3 blue; 5 blue; 1 blue");
+        assert_eq!(power(&single, &bag, MissingColors::Zero), Ok(0));
+        assert_eq!(power(&single, &bag, MissingColors::Default(1)), Ok(5));
+        assert_eq!(power(&single, &bag, MissingColors::Default(2)), Ok(20));
+
+        let blue_only: Bag = "14 blue".parse().unwrap();
+        assert_eq!(power(&single, &blue_only, MissingColors::Zero), Ok(5));
+        assert_eq!(part_2_solution(&[single, game("Game 2: 2 red")], &blue_only, MissingColors::Zero), Ok(5));
+    }
+
+    #[test]
+    fn test_power_with_a_large_missing_count() {
+        let red = game("Game 1: 3 red");
+        assert_eq!(power(&red, &Bag::default(), MissingColors::Default(100_000)), Ok(30_000_000_000));
+        assert_eq!(power(&red, &Bag::default(), MissingColors::Default(u32::MAX)), Err(Error::overflow("The power of game 1 is too large")));
+        let nothing = game("Game 2: 0 purple");
+        assert_eq!(power(&nothing, &Bag::default(), MissingColors::Default(u32::MAX)), Err(Error::overflow("The power of game 2 is too large")));
+    }
+
+    #[test]
+    fn test_power_counts_colors_the_bag_lacks() {
+        let purple = game("Game 1: 2 purple, 3 blue; 4 red, 1 green");
+        assert_eq!(power(&purple, &Bag::default(), MissingColors::Zero), Ok(24));
+        assert_eq!(power(&game("Game 2: 3 blue, 4 red"), &Bag::default(), MissingColors::Zero), Ok(0));
+        assert_eq!(power(&game("Game 2: 3 blue, 4 red"), &Bag::default(), MissingColors::Default(1)), Ok(12));
+    }
+
+    #[test]
+    fn test_large_powers() {
+        let large = game("Game 1: 100000 red, 100000 green, 100000 blue");
+        assert_eq!(power(&large, &Bag::default(), MissingColors::Zero), Ok(1_000_000_000_000_000));
+        assert_eq!(part_2_solution(&[large.clone(), large.clone()], &Bag::default(), MissingColors::Zero), Ok(2_000_000_000_000_000));
+
+        let huge = game("Game 2: 4000000000 red, 4000000000 green, 4000000000 blue");
+        assert_eq!(power(&huge, &Bag::default(), MissingColors::Zero), Err(Error::overflow("The power of game 2 is too large")));
+        let almost = game("Game 3: 4000000000 red, 4000000000 green, 1 blue");
+        assert_eq!(part_2_solution(&[almost.clone(), almost], &Bag::default(), MissingColors::Zero),
+                   Err(Error::overflow("The powers are too large to add up")));
+    }
+
+    #[test]
+    fn test_large_game_ids() {
+        let games = [game("Game 4000000000: 1 red"), game("Game 4000000000: 1 red"), game("Game 7: 13 red")];
+        assert_eq!(part_1_solution(&games, &Bag::default()), Ok(8_000_000_000));
+    }
+
+    #[test]
+    fn test_game_report() {
+        let bag = Bag::default();
+        let report = game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").report(&bag, MissingColors::Zero).unwrap();
+        assert_eq!(report.minimum.to_string(), "6 blue, 2 green, 4 red");
+        assert_eq!(report.forced_by, BTreeMap::from([(String::from("blue"), 2), (String::from("green"), 2), (String::from("red"), 1)]));
+        assert_eq!(report.power, 48);
+        assert!(report.fits);
+
+        let report = game("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red").report(&bag, MissingColors::Zero).unwrap();
+        assert_eq!(report.forced_by["green"], 2);
+        assert_eq!(report.power, 1560);
+        assert!(!report.fits);
+    }
+
+    #[test]
+    fn test_solution_on_example() {
+        let answers: Vec<_> = run_parts::<Day2>(EXAMPLE, &[1, 2], ParseMode::Strict).into_iter().map(|part| part.result).collect();
+        assert_eq!(answers, vec![Ok(Answer::Number(8)), Ok(Answer::Number(2286))]);
+    }
+
+    #[test]
+    fn test_skipped_games_are_reported() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 3 purple\nGame 3: 8 green, 6 blue, 20 red\nGame 4 1 blue";
+
+        let results = run_parts::<Day2>(input, &[1], ParseMode::Lenient);
+        assert_eq!(results[0].result, Ok(Answer::Number(1)));
+        assert_eq!(results[0].skipped, vec![
+            Error::malformed("Line was not formatted to the specification of the problem input").at_line(4),
+        ]);
+
+        let results = run_parts::<Day2>(input, &[1], ParseMode::Strict);
+        assert_eq!(results[0].result, Err(Error::malformed("Line was not formatted to the specification of the problem input").at_line(4)));
+    }
+}
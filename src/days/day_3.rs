@@ -0,0 +1,415 @@
+use std::collections::BTreeMap;
+use crate::error::Error;
+use crate::solution::{Context, Solution};
+use crate::utils::grid::Grid;
+
+pub mod visualize;
+
+/// A run of digits in the schematic. `col_end` is one past its last digit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartNumber {
+    pub value: i32,
+    pub row: usize,
+    pub col_start: usize,
+    pub col_end: usize,
+}
+
+impl PartNumber {
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (self.col_start..self.col_end).map(move |col| (self.row, col))
+    }
+}
+
+/// Which characters count as symbols.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum SymbolSet {
+    /// Anything other than a digit or `.`, as the puzzle describes.
+    #[default]
+    AnyNonDigit,
+    Only(Vec<char>),
+}
+
+impl SymbolSet {
+    pub fn contains(&self, c: char) -> bool {
+        match self {
+            SymbolSet::AnyNonDigit => c != '.' && !c.is_ascii_digit(),
+            SymbolSet::Only(symbols) => symbols.contains(&c),
+        }
+    }
+}
+
+/// Which cells around a symbol count as touching it.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Neighborhood {
+    FourWay,
+    /// Includes diagonals, as the puzzle describes.
+    #[default]
+    EightWay,
+    /// Every cell at most this many rows and columns away.
+    Radius(usize),
+}
+
+impl Neighborhood {
+    fn around(&self, grid: &Grid<char>, row: usize, col: usize) -> Vec<(usize, usize)> {
+        match self {
+            Neighborhood::FourWay => grid.neighbors_4(row, col).collect(),
+            Neighborhood::EightWay => grid.neighbors_8(row, col).collect(),
+            Neighborhood::Radius(radius) => grid.neighbors_within(row, col, *radius).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AdjacencyRules {
+    pub symbols: SymbolSet,
+    pub neighborhood: Neighborhood,
+}
+
+/// The engine schematic, with its numbers extracted once and indexed by the symbols they touch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schematic {
+    pub grid: Grid<char>,
+    pub rules: AdjacencyRules,
+    pub numbers: Vec<PartNumber>,
+    /// Every symbol's position, with the indices into `numbers` of the numbers touching it.
+    pub symbols: BTreeMap<(usize, usize), Vec<usize>>,
+}
+
+/// Finds every number in the grid, row by row.
+pub fn extract_numbers(grid: &Grid<char>) -> Result<Vec<PartNumber>, Error> {
+    let mut numbers = Vec::new();
+    for (row_index, row) in grid.rows().enumerate() {
+        let mut ptr = 0;
+        while ptr < row.len() {
+            if !row[ptr].is_ascii_digit() {
+                ptr += 1;
+                continue;
+            }
+
+            let col_start = ptr;
+            while ptr < row.len() && row[ptr].is_ascii_digit() {
+                ptr += 1;
+            }
+            let digits: String = row[col_start..ptr].iter().collect();
+            match digits.parse::<i32>() {
+                Ok(value) => numbers.push(PartNumber { value, row: row_index, col_start, col_end: ptr }),
+                Err(err) => return Err(Error::parse(&digits, err).at_line(row_index + 1).at_column(col_start + 1)),
+            }
+        }
+    }
+    Ok(numbers)
+}
+
+fn index_symbols(grid: &Grid<char>, numbers: &[PartNumber], rules: &AdjacencyRules) -> BTreeMap<(usize, usize), Vec<usize>> {
+    let mut symbols: BTreeMap<(usize, usize), Vec<usize>> = grid.positions()
+        .filter(|(row, col)| grid.get(*row, *col).is_some_and(|c| rules.symbols.contains(*c)))
+        .map(|position| (position, Vec::new()))
+        .collect();
+
+    for (number_index, number) in numbers.iter().enumerate() {
+        for (row, col) in number.cells() {
+            for position in rules.neighborhood.around(grid, row, col) {
+                if let Some(adjacent) = symbols.get_mut(&position) {
+                    // Several digits of the same number can touch one symbol
+                    if adjacent.last() != Some(&number_index) {
+                        adjacent.push(number_index);
+                    }
+                }
+            }
+        }
+    }
+    symbols
+}
+
+impl Schematic {
+    pub fn new(grid: Grid<char>) -> Result<Schematic, Error> {
+        Schematic::with_rules(grid, AdjacencyRules::default())
+    }
+
+    pub fn with_rules(grid: Grid<char>, rules: AdjacencyRules) -> Result<Schematic, Error> {
+        let numbers = extract_numbers(&grid)?;
+        let symbols = index_symbols(&grid, &numbers, &rules);
+        Ok(Schematic { grid, rules, numbers, symbols })
+    }
+
+    pub fn symbol(&self, position: (usize, usize)) -> Option<char> {
+        self.symbols.get(&position).and(self.grid.get(position.0, position.1).copied())
+    }
+
+    /// The numbers touching the symbol at `position`, in the order they appear.
+    pub fn adjacent_numbers(&self, position: (usize, usize)) -> Vec<&PartNumber> {
+        self.symbols.get(&position)
+            .map(|indices| indices.iter().map(|index| &self.numbers[*index]).collect())
+            .unwrap_or_default()
+    }
+
+    fn touching(&self, symbols: &SymbolSet) -> Vec<bool> {
+        let mut touching = vec![false; self.numbers.len()];
+        for (position, indices) in self.symbols.iter() {
+            if self.symbol(*position).is_some_and(|c| symbols.contains(c)) {
+                for index in indices {
+                    touching[*index] = true;
+                }
+            }
+        }
+        touching
+    }
+
+    /// The numbers touching at least one of `symbols`. A number touching several is only returned once.
+    pub fn numbers_adjacent_to(&self, symbols: &SymbolSet) -> Vec<&PartNumber> {
+        self.numbers.iter().zip(self.touching(symbols)).filter(|(_, touching)| *touching).map(|(number, _)| number).collect()
+    }
+
+    /// The numbers touching any symbol.
+    pub fn part_numbers(&self) -> Vec<&PartNumber> {
+        self.numbers_adjacent_to(&self.rules.symbols)
+    }
+
+    /// The numbers touching no symbol at all.
+    pub fn isolated_numbers(&self) -> Vec<&PartNumber> {
+        self.numbers.iter().zip(self.touching(&self.rules.symbols)).filter(|(_, touching)| !*touching).map(|(number, _)| number).collect()
+    }
+
+    /// Every one of `symbols` touching exactly `count` numbers, with those numbers.
+    pub fn symbols_with_exactly(&self, symbols: &SymbolSet, count: usize) -> Vec<((usize, usize), Vec<&PartNumber>)> {
+        self.symbols.keys()
+            .filter(|position| self.symbol(**position).is_some_and(|c| symbols.contains(c)))
+            .map(|position| (*position, self.adjacent_numbers(*position)))
+            .filter(|(_, numbers)| numbers.len() == count)
+            .collect()
+    }
+
+    /// Every `*` touching exactly two numbers, with those numbers.
+    pub fn gears(&self) -> Vec<((usize, usize), Vec<&PartNumber>)> {
+        self.symbols_with_exactly(&SymbolSet::Only(vec!['*']), 2)
+    }
+}
+
+/// Reads the engine schematic, checking every row has the same width.
+pub fn parse_schematic(input: &str) -> Result<Schematic, Error> {
+    Schematic::new(input.parse()?)
+}
+
+fn part_1_solution(schematic: &Schematic) -> Result<i64, Error> {
+    schematic.part_numbers().iter().try_fold(0_i64, |total, number| {
+        total.checked_add(number.value as i64).ok_or(Error::overflow("The part numbers are too large to add up"))
+    })
+}
+
+/// Gear ratios are worked out in `i64`, where the product of two numbers always fits.
+fn part_2_solution(schematic: &Schematic) -> Result<i64, Error> {
+    schematic.gears().iter().try_fold(0_i64, |total, (_, numbers)| {
+        numbers.iter()
+            .try_fold(1_i64, |ratio, number| ratio.checked_mul(number.value as i64))
+            .and_then(|ratio| total.checked_add(ratio))
+            .ok_or(Error::overflow("The gear ratios are too large to add up"))
+    })
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Schematic;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str, _context: &mut Context) -> Result<Schematic, Error> {
+        parse_schematic(input)
+    }
+
+    fn part_1(schematic: &Schematic, _context: &mut Context) -> Result<i64, Error> {
+        part_1_solution(schematic)
+    }
+
+    fn part_2(schematic: &Schematic, _context: &mut Context) -> Result<i64, Error> {
+        part_2_solution(schematic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{run_parts, Answer, ParseMode};
+
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    fn create_test_schematic() -> Schematic {
+        parse_schematic(EXAMPLE).unwrap()
+    }
+
+    fn values(numbers: &[&PartNumber]) -> Vec<i32> {
+        numbers.iter().map(|number| number.value).collect()
+    }
+
+    fn gear_values(schematic: &Schematic) -> Vec<((usize, usize), Vec<i32>)> {
+        schematic.gears().into_iter().map(|(position, numbers)| (position, values(&numbers))).collect()
+    }
+
+    #[test]
+    fn test_extract_numbers() {
+        let numbers = extract_numbers(&"467..114..\n.......755".parse().unwrap()).unwrap();
+        assert_eq!(numbers, vec![
+            PartNumber { value: 467, row: 0, col_start: 0, col_end: 3 },
+            PartNumber { value: 114, row: 0, col_start: 5, col_end: 8 },
+            PartNumber { value: 755, row: 1, col_start: 7, col_end: 10 },
+        ]);
+        assert_eq!(numbers[0].cells().collect::<Vec<_>>(), vec![(0, 0), (0, 1), (0, 2)]);
+
+        let error = parse_schematic("*............\n.99999999999.").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: could not parse '99999999999' as a number (number too large to fit in target type)");
+    }
+
+    #[test]
+    fn test_part_numbers_by_row() {
+        let schematic = create_test_schematic();
+        let part_numbers = schematic.part_numbers();
+
+        let test_cases = vec![
+            (0, vec![467]),
+            (1, vec![]),
+            (2, vec![35, 633]),
+            (3, vec![]),
+            (4, vec![617]),
+            (5, vec![]),
+            (6, vec![592]),
+            (7, vec![755]),
+            (8, vec![]),
+            (9, vec![664, 598]),
+        ];
+
+        for (row, answers) in test_cases.iter() {
+            let nums: Vec<i32> = part_numbers.iter().filter(|number| number.row == *row).map(|number| number.value).collect();
+            assert_eq!(nums, *answers);
+        }
+    }
+
+    #[test]
+    fn test_symbol_index() {
+        let schematic = create_test_schematic();
+        assert_eq!(schematic.symbols.len(), 6);
+        assert_eq!(values(&schematic.adjacent_numbers((1, 3))), vec![467, 35]);
+        assert_eq!(values(&schematic.adjacent_numbers((8, 5))), vec![755, 598]);
+        assert_eq!(values(&schematic.adjacent_numbers((4, 3))), vec![617]);
+        assert_eq!(schematic.symbol((3, 6)), Some('#'));
+        assert_eq!(schematic.symbol((0, 0)), None);
+        assert!(schematic.adjacent_numbers((0, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_gears() {
+        let schematic = create_test_schematic();
+        assert_eq!(gear_values(&schematic), vec![((1, 3), vec![467, 35]), ((8, 5), vec![755, 598])]);
+    }
+
+    #[test]
+    fn test_number_touching_two_symbols_is_counted_once() {
+        let schematic = parse_schematic("1*2*3\n.....\n#45$.").unwrap();
+        assert_eq!(part_1_solution(&schematic), Ok(1 + 2 + 3 + 45));
+        assert_eq!(part_2_solution(&schematic), Ok(2 + 2 * 3));
+        assert_eq!(values(&schematic.adjacent_numbers((2, 0))), vec![45]);
+        assert_eq!(values(&schematic.adjacent_numbers((2, 3))), vec![45]);
+    }
+
+    #[test]
+    fn test_queries_with_other_symbols() {
+        let schematic = create_test_schematic();
+        let hashes = SymbolSet::Only(vec!['#']);
+        assert_eq!(values(&schematic.numbers_adjacent_to(&hashes)), vec![633]);
+        assert_eq!(values(&schematic.numbers_adjacent_to(&SymbolSet::Only(vec!['*', '$']))), vec![467, 35, 617, 755, 664, 598]);
+        assert_eq!(values(&schematic.isolated_numbers()), vec![114, 58]);
+
+        let single: Vec<(usize, usize)> = schematic.symbols_with_exactly(&SymbolSet::AnyNonDigit, 1).into_iter().map(|(position, _)| position).collect();
+        assert_eq!(single, vec![(3, 6), (4, 3), (5, 5), (8, 3)]);
+        assert!(schematic.symbols_with_exactly(&hashes, 0).is_empty());
+    }
+
+    #[test]
+    fn test_configurable_rules() {
+        let grid: Grid<char> = "1....\n.*...\n..2..\n....3\n.#...".parse().unwrap();
+        let with = |symbols: SymbolSet, neighborhood: Neighborhood| {
+            Schematic::with_rules(grid.clone(), AdjacencyRules { symbols, neighborhood }).unwrap()
+        };
+
+        let eight_way = with(SymbolSet::AnyNonDigit, Neighborhood::EightWay);
+        assert_eq!(values(&eight_way.part_numbers()), vec![1, 2]);
+        assert_eq!(values(&eight_way.isolated_numbers()), vec![3]);
+
+        let four_way = with(SymbolSet::AnyNonDigit, Neighborhood::FourWay);
+        assert!(four_way.part_numbers().is_empty());
+
+        let radius = with(SymbolSet::AnyNonDigit, Neighborhood::Radius(3));
+        assert_eq!(values(&radius.part_numbers()), vec![1, 2, 3]);
+        assert_eq!(values(&radius.adjacent_numbers((4, 1))), vec![2, 3]);
+
+        // Only the listed characters are symbols, so dots can be too
+        let dots = with(SymbolSet::Only(vec!['.']), Neighborhood::EightWay);
+        assert_eq!(dots.symbol((1, 1)), None);
+        assert_eq!(values(&dots.part_numbers()), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_gears_on_borders_and_corners() {
+        let test_cases = vec![
+            // Borders
+            ("12*34\n.....", (0, 2), vec![12, 34]),
+            (".....\n12*34", (1, 2), vec![12, 34]),
+            ("12...\n*....\n34...", (1, 0), vec![12, 34]),
+            ("...12\n....*\n...34", (1, 4), vec![12, 34]),
+            // Corners
+            ("*12\n3..", (0, 0), vec![12, 3]),
+            ("12*\n..3", (0, 2), vec![12, 3]),
+            ("3..\n*12", (1, 0), vec![3, 12]),
+            ("..3\n12*", (1, 2), vec![3, 12]),
+            // Grids only one cell wide or tall
+            ("1*2", (0, 1), vec![1, 2]),
+            ("1\n*\n2", (1, 0), vec![1, 2]),
+        ];
+
+        for (input, position, expected) in test_cases {
+            let schematic = parse_schematic(input).unwrap();
+            assert_eq!(gear_values(&schematic), vec![(position, expected.clone())], "{}", input);
+            assert_eq!(part_2_solution(&schematic), Ok(expected.iter().product::<i32>() as i64), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_lone_stars_on_borders_are_not_gears() {
+        for schematic in ["*", "*.\n.1", "1*", "..\n.*", "*1\n..\n.*"] {
+            assert_eq!(part_2_solution(&parse_schematic(schematic).unwrap()), Ok(0), "{}", schematic);
+        }
+        assert_eq!(part_2_solution(&parse_schematic("").unwrap()), Ok(0));
+    }
+
+    #[test]
+    fn test_large_gear_ratios() {
+        let schematic = parse_schematic("99999*99999").unwrap();
+        assert_eq!(part_2_solution(&schematic), Ok(9_999_800_001));
+
+        let max = "2147483647*2147483647";
+        let spacer = ".".repeat(max.len());
+        let schematic = parse_schematic(&[max, &spacer, max, &spacer, max].join("\n")).unwrap();
+        assert_eq!(part_1_solution(&schematic), Ok(6 * 2_147_483_647));
+        assert_eq!(part_2_solution(&schematic), Err(Error::overflow("The gear ratios are too large to add up")));
+    }
+
+    #[test]
+    fn test_parse_schematic_rejects_ragged_rows() {
+        let error = parse_schematic("467..\n...*.\n..35\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 3: Expected a row 5 characters wide, found 4");
+    }
+
+    #[test]
+    fn test_solution_on_example() {
+        let answers: Vec<_> = run_parts::<Day3>(EXAMPLE, &[1, 2], ParseMode::Strict).into_iter().map(|part| part.result).collect();
+        assert_eq!(answers, vec![Ok(Answer::Number(4361)), Ok(Answer::Number(467835))]);
+    }
+}
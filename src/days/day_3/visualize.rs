@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+use std::env;
+use std::io::{self, IsTerminal};
+use super::{part_1_solution, part_2_solution, Schematic};
+
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Style {
+    /// Colours each cell with ANSI escape codes.
+    Ansi,
+    /// Prints a line of markers under each row, for output that isn't a terminal.
+    Plain,
+}
+
+impl Style {
+    /// Uses colours when stdout is a terminal, unless `NO_COLOR` is set.
+    pub fn detect() -> Style {
+        if io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none() {
+            return Style::Ansi;
+        }
+        Style::Plain
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Highlight {
+    None,
+    PartNumber,
+    OtherNumber,
+    Gear,
+    RejectedStar,
+    Symbol,
+}
+
+impl Highlight {
+    fn color(&self) -> Option<&'static str> {
+        match self {
+            Highlight::None => None,
+            Highlight::PartNumber => Some("\x1b[32m"),
+            Highlight::OtherNumber => Some("\x1b[31m"),
+            Highlight::Gear => Some("\x1b[1;33m"),
+            Highlight::RejectedStar => Some("\x1b[35m"),
+            Highlight::Symbol => Some("\x1b[36m"),
+        }
+    }
+
+    fn marker(&self) -> char {
+        match self {
+            Highlight::PartNumber => 'P',
+            Highlight::OtherNumber => 'n',
+            Highlight::Gear => 'G',
+            Highlight::RejectedStar => 'R',
+            Highlight::None | Highlight::Symbol => ' ',
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            Highlight::None => "",
+            Highlight::PartNumber => "part number",
+            Highlight::OtherNumber => "number touching no symbol",
+            Highlight::Gear => "gear",
+            Highlight::RejectedStar => "* that is not a gear",
+            Highlight::Symbol => "other symbol",
+        }
+    }
+}
+
+fn highlights(schematic: &Schematic) -> Vec<Vec<Highlight>> {
+    let mut highlights = vec![vec![Highlight::None; schematic.grid.width()]; schematic.grid.height()];
+
+    let part_numbers: HashSet<(usize, usize)> = schematic.part_numbers().iter().map(|number| (number.row, number.col_start)).collect();
+    for number in schematic.numbers.iter() {
+        let highlight = if part_numbers.contains(&(number.row, number.col_start)) { Highlight::PartNumber } else { Highlight::OtherNumber };
+        for (row, col) in number.cells() {
+            highlights[row][col] = highlight;
+        }
+    }
+
+    let gears: HashSet<(usize, usize)> = schematic.gears().iter().map(|(position, _)| *position).collect();
+    for (row, col) in schematic.symbols.keys() {
+        highlights[*row][*col] = match schematic.symbol((*row, *col)) {
+            _ if gears.contains(&(*row, *col)) => Highlight::Gear,
+            Some('*') => Highlight::RejectedStar,
+            _ => Highlight::Symbol,
+        };
+    }
+    highlights
+}
+
+fn legend(style: Style) -> String {
+    let highlights = [Highlight::PartNumber, Highlight::OtherNumber, Highlight::Gear, Highlight::RejectedStar, Highlight::Symbol];
+    let entries: Vec<String> = highlights.iter().filter_map(|highlight| match style {
+        Style::Ansi => highlight.color().map(|color| format!("{}{}{}", color, highlight.description(), RESET)),
+        Style::Plain if highlight.marker() != ' ' => Some(format!("{} {}", highlight.marker(), highlight.description())),
+        Style::Plain => None,
+    }).collect();
+    entries.join(", ")
+}
+
+/// Draws the schematic with every number and symbol marked by how the solution treated it,
+/// followed by a legend and both answers.
+pub fn render(schematic: &Schematic, style: Style) -> String {
+    let mut lines = Vec::new();
+
+    for (row, highlights) in schematic.grid.rows().zip(highlights(schematic)) {
+        match style {
+            Style::Ansi => {
+                let cells: String = row.iter().zip(highlights).map(|(c, highlight)| match highlight.color() {
+                    Some(color) => format!("{}{}{}", color, c, RESET),
+                    None => c.to_string(),
+                }).collect();
+                lines.push(cells);
+            }
+            Style::Plain => {
+                lines.push(format!("  {}", row.iter().collect::<String>()));
+                let markers: String = highlights.iter().map(|highlight| highlight.marker()).collect();
+                if !markers.trim().is_empty() {
+                    lines.push(format!("> {}", markers.trim_end()));
+                }
+            }
+        }
+    }
+
+    lines.push(String::new());
+    lines.push(legend(style));
+    let gear_ratios = match part_2_solution(schematic) {
+        Ok(sum) => format!("gear ratios sum to {}", sum),
+        Err(_) => String::from("gear ratios are too large to add up"),
+    };
+    let part_numbers = match part_1_solution(schematic) {
+        Ok(sum) => format!("Part numbers sum to {}", sum),
+        Err(_) => String::from("Part numbers are too large to add up"),
+    };
+    lines.push(format!("{}, {}", part_numbers, gear_ratios));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day_3::parse_schematic;
+
+    #[test]
+    fn test_render_plain() {
+        let schematic = parse_schematic("467..114..\n...*......\n..35..633.\n.....*..#.").unwrap();
+        assert_eq!(render(&schematic, Style::Plain), "  467..114..
+> PPP  nnn
+  ...*......
+>    G
+  ..35..633.
+>   PP  PPP
+  .....*..#.
+>      R
+
+P part number, n number touching no symbol, G gear, R * that is not a gear
+Part numbers sum to 1135, gear ratios sum to 16345");
+    }
+
+    #[test]
+    fn test_render_ansi() {
+        let schematic = parse_schematic("1*2\n.#.").unwrap();
+        let rendered = render(&schematic, Style::Ansi);
+        let first_row = rendered.lines().next().unwrap();
+        assert_eq!(first_row, "\x1b[32m1\x1b[0m\x1b[1;33m*\x1b[0m\x1b[32m2\x1b[0m");
+        assert_eq!(rendered.lines().nth(1).unwrap(), ".\x1b[36m#\x1b[0m.");
+    }
+}
@@ -0,0 +1,336 @@
+use std::cmp::min;
+use std::collections::HashSet;
+use std::str::FromStr;
+use num::{BigUint, CheckedAdd, One, Zero};
+use crate::error::Error;
+use crate::solution::{Answer, Context, Solution};
+
+/// One scratchcard, e.g. `Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Card {
+    pub id: u32,
+    pub winning: Vec<u32>,
+    pub drawn: Vec<u32>,
+}
+
+fn parse_numbers(line: &str, section: &str) -> Result<Vec<u32>, Error> {
+    section.split_whitespace()
+        .map(|token| token.parse::<u32>().map_err(|err| Error::parse(token, err).at_token(line, token)))
+        .collect()
+}
+
+impl FromStr for Card {
+    type Err = Error;
+
+    /// Parses a single line. Errors carry the column of the token that could not be read.
+    fn from_str(line: &str) -> Result<Card, Error> {
+        let Some((card_portion, numbers_portion)) = line.split_once(": ") else {
+            return Err(Error::malformed("Expected 'Card <id>: ' before the numbers"));
+        };
+        let Some(id_token) = card_portion.split_whitespace().last() else {
+            return Err(Error::malformed("Card identifier is missing"));
+        };
+        let id = id_token.parse::<u32>().map_err(|err| Error::parse(id_token, err).at_token(line, id_token))?;
+
+        let Some((winning, drawn)) = numbers_portion.split_once(" | ") else {
+            return Err(Error::malformed("Error getting draws, expected ' | ' between the winning and drawn numbers"));
+        };
+        Ok(Card { id, winning: parse_numbers(line, winning)?, drawn: parse_numbers(line, drawn)? })
+    }
+}
+
+/// How a drawn number that appears more than once on a card is matched.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Duplicates {
+    /// Every drawn copy of a winning number is a separate match.
+    #[default]
+    CountEach,
+    /// A winning number matches once, however many times it was drawn.
+    CountOnce,
+}
+
+impl Card {
+    /// The drawn numbers that are also winning numbers, in the order they were drawn.
+    pub fn matching_numbers(&self, duplicates: Duplicates) -> Vec<u32> {
+        let winning: HashSet<u32> = self.winning.iter().copied().collect();
+        let mut seen: HashSet<u32> = HashSet::new();
+        self.drawn.iter().copied()
+            .filter(|number| winning.contains(number))
+            .filter(|number| duplicates == Duplicates::CountEach || seen.insert(*number))
+            .collect()
+    }
+
+    pub fn matches(&self, duplicates: Duplicates) -> usize {
+        self.matching_numbers(duplicates).len()
+    }
+}
+
+/// How many points a card with a given number of matches is worth.
+pub enum Scoring {
+    /// 1 point for the first match, doubled for every match after it, as the puzzle describes.
+    Doubling,
+    /// 1 point per match.
+    Linear,
+    /// The nth Fibonacci number for n matches: 1, 1, 2, 3, 5, ...
+    Fibonacci,
+    /// Any other rule. Returning `None` reports the score as too large.
+    Custom(Box<dyn Fn(usize) -> Option<usize>>),
+}
+
+impl Scoring {
+    /// The points for `matches` matches, or `None` if they don't fit in a `usize`.
+    pub fn score(&self, matches: usize) -> Option<usize> {
+        match self {
+            Scoring::Doubling if matches == 0 => Some(0),
+            Scoring::Doubling => 1_usize.checked_shl(u32::try_from(matches - 1).ok()?),
+            Scoring::Linear => Some(matches),
+            Scoring::Fibonacci => {
+                let (mut current, mut next) = (0_usize, 1_usize);
+                for _ in 0..matches {
+                    (current, next) = (next, current.checked_add(next)?);
+                }
+                Some(current)
+            }
+            Scoring::Custom(score) => score(matches),
+        }
+    }
+}
+
+/// A scoring rule together with how duplicate draws are matched.
+pub struct ScoringRules {
+    pub scoring: Scoring,
+    pub duplicates: Duplicates,
+}
+
+impl Default for ScoringRules {
+    fn default() -> Self {
+        ScoringRules { scoring: Scoring::Doubling, duplicates: Duplicates::default() }
+    }
+}
+
+/// Parses every card, handing the lines that can't be parsed to the context.
+pub fn load_cards(input: &str, context: &mut Context) -> Result<Vec<Card>, Error> {
+    let mut cards: Vec<Card> = Vec::new();
+    for (line_index, line) in input.lines().enumerate() {
+        match line.parse::<Card>() {
+            Ok(card) => {
+                cards.push(card);
+            }
+            Err(err) => {
+                context.skip(err.at_line(line_index + 1))?;
+            }
+        }
+    }
+    Ok(cards)
+}
+
+/// The points every card is worth under `rules`, added together.
+pub fn total_points(cards: &[Card], rules: &ScoringRules) -> Result<usize, Error> {
+    cards.iter().try_fold(0_usize, |total, card| {
+        rules.scoring.score(card.matches(rules.duplicates))
+            .and_then(|points| total.checked_add(points))
+            .ok_or(Error::overflow(format!("The points for card {} are too large to add up", card.id)))
+    })
+}
+
+fn part_1_solution(cards: &[Card]) -> Result<usize, Error> {
+    total_points(cards, &ScoringRules::default())
+}
+
+/// How many copies of each card are held once every win has been claimed, indexed by the
+/// card's position. Each card adds its copy count to the cards it wins instead of claiming
+/// them one copy at a time. Returns `None` if a count overflows `T`.
+pub fn process_cards_for_part_2<T>(cards: &[Card], duplicates: Duplicates) -> Option<Vec<T>> where T: Clone + One + CheckedAdd {
+    let mut copies = vec![T::one(); cards.len()];
+
+    for (index, card) in cards.iter().enumerate() {
+        let last_won = min(index + card.matches(duplicates), cards.len() - 1);
+        for won in index + 1..=last_won {
+            copies[won] = copies[won].checked_add(&copies[index])?;
+        }
+    }
+    Some(copies)
+}
+
+fn total_cards<T>(cards: &[Card]) -> Option<T> where T: Clone + Zero + One + CheckedAdd {
+    process_cards_for_part_2::<T>(cards, Duplicates::default())?.iter().try_fold(T::zero(), |total, copies| total.checked_add(copies))
+}
+
+/// Counts grow exponentially when cards win many others, so this falls back to
+/// arbitrary precision when they no longer fit in a `usize`.
+fn part_2_solution(cards: &[Card]) -> Answer {
+    match total_cards::<usize>(cards) {
+        Some(total) => Answer::from(total),
+        None => Answer::from(total_cards::<BigUint>(cards).expect("adding big integers can't overflow")),
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+    type Answer1 = usize;
+    type Answer2 = Answer;
+
+    fn parse(input: &str, context: &mut Context) -> Result<Vec<Card>, Error> {
+        load_cards(input, context)
+    }
+
+    fn part_1(cards: &Vec<Card>, _context: &mut Context) -> Result<usize, Error> {
+        part_1_solution(cards)
+    }
+
+    fn part_2(cards: &Vec<Card>, _context: &mut Context) -> Result<Answer, Error> {
+        Ok(part_2_solution(cards))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{run_parts, ParseMode};
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    fn card(line: &str) -> Card {
+        line.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_card() {
+        assert_eq!(card("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"), Card {
+            id: 1,
+            winning: vec![41, 48, 83, 86, 17],
+            drawn: vec![83, 86, 6, 31, 17, 9, 48, 53],
+        });
+        assert_eq!(card("Card   3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1"), Card {
+            id: 3,
+            winning: vec![1, 21, 53, 59, 44],
+            drawn: vec![69, 82, 63, 72, 16, 21, 14, 1],
+        });
+    }
+
+    #[test]
+    fn test_parse_card_errors() {
+        let error = |line: &str| line.parse::<Card>().unwrap_err().to_string();
+
+        assert_eq!(error("Card 1 41 48 | 83 86"), "Expected 'Card <id>: ' before the numbers");
+        assert_eq!(error(": 41 48 | 83 86"), "Card identifier is missing");
+        assert_eq!(error("Card 1: 41 48 83 86"), "Error getting draws, expected ' | ' between the winning and drawn numbers");
+        assert_eq!(error("Card 2: 41 4x | 83 86"), "column 12: could not parse '4x' as a number (invalid digit found in string)");
+        assert_eq!(error("Card 2: 41 48 | 83 -86"), "column 20: could not parse '-86' as a number (invalid digit found in string)");
+    }
+
+    #[test]
+    fn test_score() {
+        let doubling = ScoringRules::default();
+        assert_eq!(total_points(&[card("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53")], &doubling), Ok(8));
+        assert_eq!(total_points(&[card("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1")], &doubling), Ok(2));
+
+        let scores = |scoring: Scoring| -> Vec<Option<usize>> { (0..=6).map(|matches| scoring.score(matches)).collect() };
+        assert_eq!(scores(Scoring::Doubling), vec![Some(0), Some(1), Some(2), Some(4), Some(8), Some(16), Some(32)]);
+        assert_eq!(scores(Scoring::Linear), vec![Some(0), Some(1), Some(2), Some(3), Some(4), Some(5), Some(6)]);
+        assert_eq!(scores(Scoring::Fibonacci), vec![Some(0), Some(1), Some(1), Some(2), Some(3), Some(5), Some(8)]);
+        assert_eq!(scores(Scoring::Custom(Box::new(|matches| Some(matches * matches)))), vec![Some(0), Some(1), Some(4), Some(9), Some(16), Some(25), Some(36)]);
+
+        assert_eq!(Scoring::Doubling.score(200), None);
+        assert_eq!(Scoring::Fibonacci.score(200), None);
+    }
+
+    #[test]
+    fn test_scoring_variants_on_example() {
+        let cards = load_cards(EXAMPLE, &mut Context::default()).unwrap();
+        let points = |scoring: Scoring| total_points(&cards, &ScoringRules { scoring, duplicates: Duplicates::CountEach });
+
+        assert_eq!(points(Scoring::Doubling), Ok(13));
+        assert_eq!(points(Scoring::Linear), Ok(4 + 2 + 2 + 1));
+        assert_eq!(points(Scoring::Fibonacci), Ok(3 + 1 + 1 + 1));
+        let bonus = 10;
+        assert_eq!(points(Scoring::Custom(Box::new(move |matches| Some(if matches > 0 { bonus } else { 0 })))), Ok(40));
+    }
+
+    #[test]
+    fn test_duplicate_draws() {
+        let repeated = card("Card 1: 41 48 | 41 41 48 41");
+        assert_eq!(repeated.matching_numbers(Duplicates::CountEach), vec![41, 41, 48, 41]);
+        assert_eq!(repeated.matching_numbers(Duplicates::CountOnce), vec![41, 48]);
+
+        let count_once = ScoringRules { scoring: Scoring::Doubling, duplicates: Duplicates::CountOnce };
+        assert_eq!(total_points(std::slice::from_ref(&repeated), &ScoringRules::default()), Ok(8));
+        assert_eq!(total_points(std::slice::from_ref(&repeated), &count_once), Ok(2));
+
+        let cards = vec![repeated, card("Card 2: 1 | 2"), card("Card 3: 1 | 2"), card("Card 4: 1 | 2"), card("Card 5: 1 | 2")];
+        assert_eq!(process_cards_for_part_2::<usize>(&cards, Duplicates::CountEach), Some(vec![1, 2, 2, 2, 2]));
+        assert_eq!(process_cards_for_part_2::<usize>(&cards, Duplicates::CountOnce), Some(vec![1, 2, 2, 1, 1]));
+    }
+
+    #[test]
+    fn test_total_points_reports_overflow() {
+        let numbers: Vec<u32> = (1..=100).collect();
+        let card = Card { id: 7, winning: numbers.clone(), drawn: numbers };
+        assert_eq!(total_points(&[card], &ScoringRules::default()), Err(Error::overflow("The points for card 7 are too large to add up")));
+    }
+
+    #[test]
+    fn test_matching_numbers() {
+        assert_eq!(card("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").matching_numbers(Duplicates::CountEach), vec![83, 86, 17, 48]);
+        assert_eq!(card("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1").matching_numbers(Duplicates::CountEach), vec![21, 1]);
+        assert_eq!(card("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36").matching_numbers(Duplicates::CountEach), vec![]);
+    }
+
+    #[test]
+    fn test_load_cards_reports_line() {
+        let mut context = Context::new(ParseMode::Strict);
+        let error = load_cards("Card 1: 41 48 | 83 86\nCard x: 13 32 | 61 30", &mut context).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 6: could not parse 'x' as a number (invalid digit found in string)");
+
+        let mut context = Context::new(ParseMode::Lenient);
+        let cards = load_cards("Card 1: 41 48 | 83 86\nCard 2: 13 32 20 16 61\n", &mut context).unwrap();
+        assert_eq!(cards.len(), 1);
+        assert_eq!(context.skipped, vec![
+            Error::malformed("Error getting draws, expected ' | ' between the winning and drawn numbers").at_line(2)
+        ]);
+    }
+
+    #[test]
+    fn test_process_cards_for_part_2() {
+        let cards = load_cards(EXAMPLE, &mut Context::default()).unwrap();
+
+        assert_eq!(process_cards_for_part_2::<usize>(&cards, Duplicates::CountEach), Some(vec![1, 2, 4, 8, 14, 1]));
+        assert_eq!(process_cards_for_part_2::<usize>(&[], Duplicates::CountEach), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_process_cards_for_part_2_scales_with_cards() {
+        // Card n ends up with n copies, which took a step per copy to count before
+        let cards: Vec<Card> = (1..=100_000).map(|id| Card { id, winning: vec![7], drawn: vec![7] }).collect();
+        let copies = process_cards_for_part_2::<usize>(&cards, Duplicates::CountEach).unwrap();
+        assert_eq!(copies[99_999], 100_000);
+        assert_eq!(copies.iter().sum::<usize>(), 5_000_050_000);
+    }
+
+    #[test]
+    fn test_card_counts_that_overflow() {
+        // Every card wins all the cards after it, so card n ends up with 2^(n-1) copies
+        let numbers: Vec<u32> = (1..=100).collect();
+        let cards: Vec<Card> = (1..=100).map(|id| Card { id, winning: numbers.clone(), drawn: numbers.clone() }).collect();
+
+        assert_eq!(process_cards_for_part_2::<u32>(&cards, Duplicates::CountEach), None);
+        assert_eq!(total_cards::<usize>(&cards), None);
+
+        let answer = part_2_solution(&cards);
+        assert_eq!(answer, Answer::Big(BigUint::from(2_u8).pow(100) - 1_u8));
+        assert_eq!(answer.to_string(), "1267650600228229401496703205375");
+    }
+
+    #[test]
+    fn test_solution_on_example() {
+        let answers: Vec<_> = run_parts::<Day4>(EXAMPLE, &[1, 2], ParseMode::Strict).into_iter().map(|part| part.result).collect();
+        assert_eq!(answers, vec![Ok(Answer::Number(13)), Ok(Answer::Number(30))]);
+    }
+}
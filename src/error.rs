@@ -0,0 +1,171 @@
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Where in the puzzle input something went wrong. Lines and columns start at 1.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Position {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column),
+            (Some(line), None) => write!(f, "line {}: ", line),
+            (None, Some(column)) => write!(f, "column {}: ", column),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Error {
+    /// The input could not be read. A missing path means the input came from stdin.
+    Io { path: Option<PathBuf>, source: Arc<io::Error> },
+    /// A token that should have been a number was not one.
+    Parse { position: Position, token: String, source: ParseIntError },
+    /// The input does not have the structure the puzzle describes.
+    Malformed { position: Position, message: String },
+    /// The input is fine, but an answer grew too large for the type it is computed in.
+    Overflow(String),
+    UnknownDay(u32),
+    UnknownPart(u32),
+}
+
+impl Error {
+    pub fn io(path: Option<PathBuf>, source: io::Error) -> Self {
+        Error::Io { path, source: Arc::new(source) }
+    }
+
+    pub fn parse(token: &str, source: ParseIntError) -> Self {
+        Error::Parse { position: Position::default(), token: token.to_string(), source }
+    }
+
+    pub fn malformed<S: Into<String>>(message: S) -> Self {
+        Error::Malformed { position: Position::default(), message: message.into() }
+    }
+
+    pub fn overflow<S: Into<String>>(message: S) -> Self {
+        Error::Overflow(message.into())
+    }
+
+    fn position_mut(&mut self) -> Option<&mut Position> {
+        match self {
+            Error::Parse { position, .. } | Error::Malformed { position, .. } => Some(position),
+            _ => None,
+        }
+    }
+
+    /// Records the line the error happened on, unless a more specific one is already known.
+    pub fn at_line(mut self, line: usize) -> Self {
+        if let Some(position) = self.position_mut() {
+            position.line.get_or_insert(line);
+        }
+        self
+    }
+
+    /// Records the column the error happened on, unless a more specific one is already known.
+    pub fn at_column(mut self, column: usize) -> Self {
+        if let Some(position) = self.position_mut() {
+            position.column.get_or_insert(column);
+        }
+        self
+    }
+
+    /// Records the column of `token`, which must be a slice of `line`.
+    pub fn at_token(mut self, line: &str, token: &str) -> Self {
+        if let Some(position) = self.position_mut() {
+            let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+            if offset <= line.len() {
+                position.column.get_or_insert(offset + 1);
+            }
+        }
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path: Some(path), source } => write!(f, "Could not read {}: {}", path.display(), source),
+            Error::Io { path: None, source } => write!(f, "Could not read stdin: {}", source),
+            Error::Parse { position, token, source } => {
+                write!(f, "{}could not parse '{}' as a number ({})", position, token, source)
+            }
+            Error::Malformed { position, message } => write!(f, "{}{}", position, message),
+            Error::Overflow(message) => write!(f, "{}", message),
+            Error::UnknownDay(day) => write!(f, "Day {} has not been solved yet", day),
+            Error::UnknownPart(part) => write!(f, "Puzzles only have two parts, there is no part {}", part),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source.as_ref()),
+            Error::Parse { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Io errors are compared by kind, since `io::Error` itself can't be compared.
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Error::Io { path, source }, Error::Io { path: other_path, source: other_source }) => {
+                path == other_path && source.kind() == other_source.kind()
+            }
+            (Error::Parse { position, token, source }, Error::Parse { position: other_position, token: other_token, source: other_source }) => {
+                position == other_position && token == other_token && source == other_source
+            }
+            (Error::Malformed { position, message }, Error::Malformed { position: other_position, message: other_message }) => {
+                position == other_position && message == other_message
+            }
+            (Error::Overflow(message), Error::Overflow(other_message)) => message == other_message,
+            (Error::UnknownDay(day), Error::UnknownDay(other_day)) => day == other_day,
+            (Error::UnknownPart(part), Error::UnknownPart(other_part)) => part == other_part,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_is_recorded_once() {
+        let line = "Game 12: 3 blue";
+        let token = &line[5..7];
+        let error = Error::parse(token, "x".parse::<i32>().unwrap_err())
+            .at_token(line, token)
+            .at_line(4)
+            .at_line(9);
+
+        match &error {
+            Error::Parse { position, .. } => assert_eq!(*position, Position { line: Some(4), column: Some(6) }),
+            _ => panic!("Expected a parse error"),
+        }
+        assert_eq!(error.to_string(), "line 4, column 6: could not parse '12' as a number (invalid digit found in string)");
+    }
+
+    #[test]
+    fn test_malformed_display() {
+        assert_eq!(Error::malformed("Missing ': '").to_string(), "Missing ': '");
+        assert_eq!(Error::malformed("Missing ': '").at_line(2).to_string(), "line 2: Missing ': '");
+        assert_eq!(Error::UnknownPart(3).at_line(2), Error::UnknownPart(3));
+    }
+
+    #[test]
+    fn test_overflow_has_no_position() {
+        let error = Error::overflow("The points for card 7 are too large to add up").at_line(7);
+        assert_eq!(error, Error::overflow("The points for card 7 are too large to add up"));
+        assert_eq!(error.to_string(), "The points for card 7 are too large to add up");
+    }
+}
@@ -0,0 +1,240 @@
+use std::time::Duration;
+use crate::solution::Answer;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum OutputFormat {
+    /// The plain sentences printed by `print_solution`.
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl OutputFormat {
+    pub fn parse(value: &str) -> Option<OutputFormat> {
+        match value {
+            "text" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            "csv" => Some(OutputFormat::Csv),
+            "markdown" | "md" => Some(OutputFormat::Markdown),
+            _ => None,
+        }
+    }
+}
+
+/// One day and part of a run, flattened for the machine readable formats.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub day: u32,
+    pub part: u32,
+    pub status: String,
+    pub answer: Option<Answer>,
+    pub elapsed: Duration,
+    pub skipped: usize,
+    /// The error message, or what a regression expected.
+    pub details: Option<String>,
+}
+
+const COLUMNS: [&str; 7] = ["day", "part", "status", "answer", "elapsed_ms", "skipped", "details"];
+
+/// One cell of a table. Numbers are written as they are, everything else is escaped
+/// as each format needs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(String),
+    Text(String),
+    Bool(bool),
+    Null,
+}
+
+impl Value {
+    fn to_json(&self) -> String {
+        match self {
+            Value::Number(number) => number.clone(),
+            Value::Text(text) => json_string(text),
+            Value::Bool(value) => value.to_string(),
+            Value::Null => String::from("null"),
+        }
+    }
+
+    fn to_plain(&self) -> String {
+        match self {
+            Value::Number(text) | Value::Text(text) => text.clone(),
+            Value::Bool(value) => value.to_string(),
+            Value::Null => String::new(),
+        }
+    }
+}
+
+fn elapsed_ms(elapsed: Duration) -> String {
+    format!("{:.3}", elapsed.as_secs_f64() * 1000.0)
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", value.replace('"', "\"\""));
+    }
+    value.to_string()
+}
+
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+fn to_json(columns: &[&str], rows: &[Vec<Value>]) -> String {
+    let objects: Vec<String> = rows.iter().map(|row| {
+        let fields: Vec<String> = columns.iter().zip(row).map(|(column, value)| format!("{}: {}", json_string(column), value.to_json())).collect();
+        format!("  {{{}}}", fields.join(", "))
+    }).collect();
+
+    if objects.is_empty() {
+        return String::from("[]");
+    }
+    format!("[\n{}\n]", objects.join(",\n"))
+}
+
+fn to_csv(columns: &[&str], rows: &[Vec<Value>]) -> String {
+    let mut lines = vec![columns.join(",")];
+    for row in rows {
+        lines.push(row.iter().map(|value| csv_field(&value.to_plain())).collect::<Vec<String>>().join(","));
+    }
+    lines.join("\n")
+}
+
+fn to_markdown(columns: &[&str], rows: &[Vec<Value>]) -> String {
+    let mut lines = vec![
+        format!("| {} |", columns.join(" | ")),
+        format!("|{}", "---|".repeat(columns.len())),
+    ];
+    for row in rows {
+        lines.push(format!("| {} |", row.iter().map(|value| markdown_cell(&value.to_plain())).collect::<Vec<String>>().join(" | ")));
+    }
+    lines.join("\n")
+}
+
+/// Renders any table in one of the machine readable formats, returning `None` for text.
+/// Every row should have a value for each column.
+pub fn render_table(format: OutputFormat, columns: &[&str], rows: &[Vec<Value>]) -> Option<String> {
+    match format {
+        OutputFormat::Text => None,
+        OutputFormat::Json => Some(to_json(columns, rows)),
+        OutputFormat::Csv => Some(to_csv(columns, rows)),
+        OutputFormat::Markdown => Some(to_markdown(columns, rows)),
+    }
+}
+
+fn values(row: &Row) -> Vec<Value> {
+    let answer = match &row.answer {
+        Some(Answer::Number(number)) => Value::Number(number.to_string()),
+        // JSON numbers have no size limit, although some readers lose precision on them
+        Some(Answer::Big(number)) => Value::Number(number.to_string()),
+        Some(answer) => Value::Text(answer.to_string()),
+        None => Value::Null,
+    };
+    vec![
+        Value::Number(row.day.to_string()),
+        Value::Number(row.part.to_string()),
+        Value::Text(row.status.clone()),
+        answer,
+        Value::Number(elapsed_ms(row.elapsed)),
+        Value::Number(row.skipped.to_string()),
+        row.details.clone().map(Value::Text).unwrap_or(Value::Null),
+    ]
+}
+
+/// Renders the rows in one of the machine readable formats. Text output is left to
+/// the caller, so this returns `None` for it.
+pub fn render(format: OutputFormat, rows: &[Row]) -> Option<String> {
+    render_table(format, &COLUMNS, &rows.iter().map(values).collect::<Vec<Vec<Value>>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num::BigUint;
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row {
+                day: 1,
+                part: 2,
+                status: String::from("ok"),
+                answer: Some(Answer::Number(281)),
+                elapsed: Duration::from_micros(1500),
+                skipped: 1,
+                details: None,
+            },
+            Row {
+                day: 4,
+                part: 1,
+                status: String::from("error"),
+                answer: None,
+                elapsed: Duration::ZERO,
+                skipped: 0,
+                details: Some(String::from("line 2: Error getting draws, expected ' | '")),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_render_json() {
+        assert_eq!(render(OutputFormat::Json, &rows()).unwrap(), "[
+  {\"day\": 1, \"part\": 2, \"status\": \"ok\", \"answer\": 281, \"elapsed_ms\": 1.500, \"skipped\": 1, \"details\": null},
+  {\"day\": 4, \"part\": 1, \"status\": \"error\", \"answer\": null, \"elapsed_ms\": 0.000, \"skipped\": 0, \"details\": \"line 2: Error getting draws, expected ' | '\"}
+]");
+        assert_eq!(render(OutputFormat::Json, &[]).unwrap(), "[]");
+        let big = Row { answer: Some(Answer::Big(BigUint::from(u64::MAX))), ..rows()[0].clone() };
+        assert!(render(OutputFormat::Json, &[big]).unwrap().contains("\"answer\": 18446744073709551615,"));
+        assert_eq!(json_string("a \"b\"\n"), "\"a \\\"b\\\"\\n\"");
+    }
+
+    #[test]
+    fn test_render_csv() {
+        assert_eq!(render(OutputFormat::Csv, &rows()).unwrap(), "day,part,status,answer,elapsed_ms,skipped,details
+1,2,ok,281,1.500,1,
+4,1,error,,0.000,0,\"line 2: Error getting draws, expected ' | '\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        assert_eq!(render(OutputFormat::Markdown, &rows()).unwrap(), "| day | part | status | answer | elapsed_ms | skipped | details |
+|---|---|---|---|---|---|---|
+| 1 | 2 | ok | 281 | 1.500 | 1 |  |
+| 4 | 1 | error |  | 0.000 | 0 | line 2: Error getting draws, expected ' \\| ' |");
+        assert_eq!(render(OutputFormat::Text, &rows()), None);
+    }
+
+    #[test]
+    fn test_render_table() {
+        let rows = vec![
+            vec![Value::Number(String::from("1")), Value::Bool(true), Value::Text(String::from("a, b"))],
+            vec![Value::Number(String::from("2")), Value::Bool(false), Value::Null],
+        ];
+        assert_eq!(render_table(OutputFormat::Json, &["game", "fits", "note"], &rows).unwrap(), "[
+  {\"game\": 1, \"fits\": true, \"note\": \"a, b\"},
+  {\"game\": 2, \"fits\": false, \"note\": null}
+]");
+        assert_eq!(render_table(OutputFormat::Csv, &["game", "fits", "note"], &rows).unwrap(), "game,fits,note
+1,true,\"a, b\"
+2,false,");
+    }
+}
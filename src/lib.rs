@@ -0,0 +1,23 @@
+//! Solutions to advent of code 2023, usable as a library as well as through the binary.
+//!
+//! Every day lives in [`days`] and implements [`solution::Solution`], so its parser and
+//! answers can be used directly:
+//!
+//! ```
+//! use advent_of_code_2023::days::day_4::Day4;
+//! use advent_of_code_2023::solution::{run_parts, Answer, ParseMode};
+//!
+//! let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+//! let results = run_parts::<Day4>(input, &[1], ParseMode::Strict);
+//! assert_eq!(results[0].result, Ok(Answer::Number(8)));
+//! ```
+
+pub mod bench;
+pub mod cli;
+pub mod days;
+pub mod error;
+pub mod format;
+pub mod runner;
+pub mod solution;
+pub mod utils;
+pub mod verify;
@@ -1,22 +1,101 @@
 extern crate core;
 
-mod day_1;
-mod utils;
-mod day_2;
-mod day_3;
-mod day_4;
+use std::env;
+use std::process::ExitCode;
+use advent_of_code_2023::{bench, cli, runner, verify};
+use advent_of_code_2023::cli::Command;
+use advent_of_code_2023::days::day_3::visualize::Style;
+use advent_of_code_2023::utils::file::input_dir;
+use advent_of_code_2023::verify::{KnownAnswers, ANSWERS_FILE};
 
+fn main() -> ExitCode {
+    match cli::parse_args(env::args().skip(1)) {
+        Ok(Command::Run(options)) => {
+            let results = runner::run(&options);
+            runner::report(&results, options.format);
 
-fn main() {
-    day_1::part_1_solution();
-    day_1::part_2_solution();
+            if results.iter().any(|run| run.result.is_err()) {
+                return ExitCode::FAILURE;
+            }
+            ExitCode::SUCCESS
+        }
+        Ok(Command::Verify(options)) => {
+            let known_answers = match KnownAnswers::load(&input_dir().join(ANSWERS_FILE)) {
+                Ok(known_answers) => known_answers,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
 
-    day_2::part_1_solution();
-    day_2::part_2_solution();
+            if verify::report(&runner::run(&options), &known_answers, options.format) {
+                return ExitCode::SUCCESS;
+            }
+            ExitCode::FAILURE
+        }
+        Ok(Command::Bench { options, runs }) => {
+            let results = bench::run(&options, runs);
+            bench::report(&results, runs);
 
-    day_3::part_1_solution();
-    day_3::part_2_solution();
-
-    day_4::part_1_solution();
-    day_4::part_2_solution();
+            if results.iter().any(|result| result.timings.is_err()) {
+                return ExitCode::FAILURE;
+            }
+            ExitCode::SUCCESS
+        }
+        Ok(Command::Visualize { options, style }) => {
+            match runner::visualize(&options, style.unwrap_or_else(Style::detect)) {
+                Ok(drawing) => {
+                    println!("{}", drawing);
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("{}", err);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Ok(Command::Bags { options, bags }) => {
+            match runner::check_bags(&options, &bags) {
+                Ok(report) => {
+                    println!("{}", report);
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("{}", err);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Ok(Command::GameReport { options, bags, missing }) => {
+            match runner::game_report(&options, &bags, missing) {
+                Ok(report) => {
+                    println!("{}", report);
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("{}", err);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Ok(Command::Missing) => {
+            let input_dir = input_dir();
+            let missing = runner::missing_inputs(&input_dir);
+            if missing.is_empty() {
+                println!("Every day has an input in {}", input_dir.display());
+            }
+            for (day, path) in missing {
+                println!("Day {} has no input, expected {}", day, path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            ExitCode::SUCCESS
+        }
+        Err(msg) => {
+            eprintln!("{}\n\n{}", msg, cli::USAGE);
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -0,0 +1,278 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use crate::cli::{BagSource, InputSource, RunOptions};
+use crate::days;
+use crate::days::Day;
+use crate::days::day_2::{load_games, parse_bags, sum_ids, Bag, GameReport, MissingColors};
+use crate::days::day_3::parse_schematic;
+use crate::days::day_3::visualize::{self, Style};
+use crate::error::Error;
+use crate::format::{render, render_table, OutputFormat, Row, Value};
+use crate::solution::{Answer, Context, PartResult};
+use crate::utils::file::{input_dir, read_input, read_stdin};
+use crate::utils::output::{format_duration, print_solution};
+
+#[derive(Debug)]
+pub struct RunResult {
+    pub day: u32,
+    pub part: u32,
+    pub result: Result<Answer, Error>,
+    pub skipped: Vec<Error>,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+impl RunResult {
+    /// Flattens the result for the machine readable formats, using the given status.
+    pub fn to_row(&self, status: String, details: Option<String>) -> Row {
+        Row {
+            day: self.day,
+            part: self.part,
+            status,
+            answer: self.result.clone().ok(),
+            elapsed: self.parse_time + self.solve_time,
+            skipped: self.skipped.len(),
+            details: details.or(self.result.as_ref().err().map(|err| err.to_string())),
+        }
+    }
+}
+
+fn failed_parts(parts: &[u32], error: Error) -> Vec<PartResult> {
+    parts.iter().map(|part| PartResult {
+        part: *part,
+        result: Err(error.clone()),
+        skipped: Vec::new(),
+        parse_time: Duration::ZERO,
+        solve_time: Duration::ZERO,
+    }).collect()
+}
+
+pub fn load_input(day: &Day, options: &RunOptions) -> Result<String, Error> {
+    match &options.input {
+        Some(InputSource::Stdin) => read_stdin(),
+        Some(InputSource::File(path)) => read_input(path),
+        None => read_input(day.input_path(&input_dir())),
+    }
+}
+
+/// Runs every selected day and part, collecting the answers without printing them.
+pub fn run(options: &RunOptions) -> Vec<RunResult> {
+    let available: Vec<u32> = days::registry().iter().map(|day| day.number).collect();
+    let mut results = Vec::new();
+
+    for number in options.selected_days(&available) {
+        let part_results = match days::find_day(number) {
+            Some(day) => {
+                match load_input(&day, options) {
+                    Ok(input) => (day.run)(&input, &options.parts, options.mode),
+                    Err(err) => failed_parts(&options.parts, err),
+                }
+            }
+            None => failed_parts(&options.parts, Error::UnknownDay(number)),
+        };
+
+        results.extend(part_results.into_iter().map(|PartResult { part, result, skipped, parse_time, solve_time }| {
+            RunResult { day: number, part, result, skipped, parse_time, solve_time }
+        }));
+    }
+    results
+}
+
+/// Loads the day 3 input and draws its schematic.
+pub fn visualize(options: &RunOptions, style: Style) -> Result<String, Error> {
+    let day = days::find_day(3).ok_or(Error::UnknownDay(3))?;
+    let schematic = parse_schematic(&load_input(&day, options)?)?;
+    Ok(visualize::render(&schematic, style))
+}
+
+fn load_bags(sources: &[BagSource]) -> Result<Vec<Bag>, Error> {
+    let mut bags = Vec::new();
+    for source in sources {
+        match source {
+            BagSource::Cubes(bag) => bags.push(bag.clone()),
+            BagSource::File(path) => bags.extend(parse_bags(&read_input(path)?)?),
+        }
+    }
+    Ok(bags)
+}
+
+/// Loads the day 2 input and lists the games that are possible with each bag.
+pub fn check_bags(options: &RunOptions, sources: &[BagSource]) -> Result<String, Error> {
+    let bags = load_bags(sources)?;
+    let day = days::find_day(2).ok_or(Error::UnknownDay(2))?;
+    let mut context = Context::new(options.mode);
+    let games = load_games(&load_input(&day, options)?, &mut context)?;
+
+    let mut lines = Vec::new();
+    for bag in bags {
+        let possible = bag.possible_games(&games);
+        let ids: Vec<String> = possible.iter().map(|game| game.id.to_string()).collect();
+        lines.push(format!("Bag {}: {} of {} games possible, ids sum to {}",
+                           bag, possible.len(), games.len(), sum_ids(&possible)?));
+        if !ids.is_empty() {
+            lines.push(format!("  {}", ids.join(", ")));
+        }
+    }
+
+    if !context.skipped.is_empty() {
+        lines.push(format!("Skipped {} line(s) that could not be parsed:", context.skipped.len()));
+        lines.extend(context.skipped.iter().map(|err| format!("  {}", err)));
+    }
+    Ok(lines.join("\n"))
+}
+
+fn game_report_text(reports: &[GameReport], bag: &Bag) -> Vec<String> {
+    let mut lines = Vec::new();
+    for report in reports {
+        lines.push(format!("Game {}: needs {} (power {}), {} {}",
+                           report.id, report.minimum, report.power, if report.fits { "fits" } else { "does not fit" }, bag));
+        let forced: Vec<String> = report.forced_by.iter().map(|(color, draw)| format!("{} from draw {}", color, draw)).collect();
+        if !forced.is_empty() {
+            lines.push(format!("  {}", forced.join(", ")));
+        }
+    }
+    lines
+}
+
+/// One column for the minimum of each colour and one for the draw that forced it. Lines that
+/// could not be parsed get a row of their own with a `skipped` status and the error as details.
+fn game_report_table(reports: &[GameReport], bag: &Bag, skipped: &[Error]) -> (Vec<String>, Vec<Vec<Value>>) {
+    let colors: BTreeSet<&String> = bag.cubes.keys().chain(reports.iter().flat_map(|report| report.minimum.cubes.keys())).collect();
+
+    let mut columns = vec![String::from("game"), String::from("status"), String::from("fits"), String::from("power")];
+    for color in colors.iter() {
+        columns.push(color.to_string());
+        columns.push(format!("{}_draw", color));
+    }
+    columns.push(String::from("details"));
+
+    let mut rows: Vec<Vec<Value>> = reports.iter().map(|report| {
+        let mut row = vec![
+            Value::Number(report.id.to_string()),
+            Value::Text(String::from("ok")),
+            Value::Bool(report.fits),
+            Value::Number(report.power.to_string()),
+        ];
+        for color in colors.iter() {
+            row.push(report.minimum.cubes.get(*color).map(|count| Value::Number(count.to_string())).unwrap_or(Value::Null));
+            row.push(report.forced_by.get(*color).map(|draw| Value::Number(draw.to_string())).unwrap_or(Value::Null));
+        }
+        row.push(Value::Null);
+        row
+    }).collect();
+
+    for err in skipped {
+        let mut row = vec![Value::Null, Value::Text(String::from("skipped"))];
+        row.resize(columns.len() - 1, Value::Null);
+        row.push(Value::Text(err.to_string()));
+        rows.push(row);
+    }
+    (columns, rows)
+}
+
+fn render_game_report(reports: &[GameReport], bag: &Bag, skipped: &[Error], format: OutputFormat) -> String {
+    let (columns, rows) = game_report_table(reports, bag, skipped);
+    let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
+    if let Some(output) = render_table(format, &columns, &rows) {
+        return output;
+    }
+
+    let mut lines = game_report_text(reports, bag);
+    if !skipped.is_empty() {
+        lines.push(format!("Skipped {} line(s) that could not be parsed:", skipped.len()));
+        lines.extend(skipped.iter().map(|err| format!("  {}", err)));
+    }
+    lines.join("\n")
+}
+
+/// Loads the day 2 input and reports what each game needed, checked against the one
+/// configured bag or the puzzle bag. The bag's colours also decide which colours count
+/// towards each power.
+pub fn game_report(options: &RunOptions, sources: &[BagSource], missing: MissingColors) -> Result<String, Error> {
+    let mut bags = load_bags(sources)?;
+    if bags.len() > 1 {
+        return Err(Error::malformed(format!("A report checks a single bag, {} were given", bags.len())));
+    }
+    let bag = bags.pop().unwrap_or_default();
+    let day = days::find_day(2).ok_or(Error::UnknownDay(2))?;
+    let mut context = Context::new(options.mode);
+    let games = load_games(&load_input(&day, options)?, &mut context)?;
+    let reports = games.iter().map(|game| game.report(&bag, missing)).collect::<Result<Vec<GameReport>, Error>>()?;
+    Ok(render_game_report(&reports, &bag, &context.skipped, options.format))
+}
+
+/// Lists the registered days whose input file is not present in `input_dir`.
+pub fn missing_inputs(input_dir: &Path) -> Vec<(u32, PathBuf)> {
+    days::registry().iter()
+        .map(|day| (day.number, day.input_path(input_dir)))
+        .filter(|(_number, path)| !path.is_file())
+        .collect()
+}
+
+pub fn report(results: &[RunResult], format: OutputFormat) {
+    let rows: Vec<Row> = results.iter().map(|run| {
+        let status = if run.result.is_ok() { "ok" } else { "error" };
+        run.to_row(String::from(status), None)
+    }).collect();
+    if let Some(output) = render(format, &rows) {
+        println!("{}", output);
+        return;
+    }
+
+    for RunResult { day, part, result, skipped, parse_time, solve_time } in results {
+        print_solution(*day as i32, *part as i32, result);
+        if result.is_ok() {
+            println!("  parse {}, solve {}", format_duration(*parse_time), format_duration(*solve_time));
+        }
+
+        if !skipped.is_empty() {
+            println!("  Skipped {} line(s) that could not be parsed:", skipped.len());
+            for err in skipped {
+                println!("    {}", err);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day_2::Game;
+
+    fn reports(bag: &Bag) -> Vec<GameReport> {
+        ["Game 1: 3 blue, 4 red; 2 green, 6 blue", "Game 2: 20 red"].iter()
+            .map(|line| line.parse::<Game>().unwrap().report(bag, MissingColors::Default(1)).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_game_report_csv() {
+        let bag: Bag = "12 red, 14 blue".parse().unwrap();
+        let skipped = [Error::malformed("Line was not formatted to the specification of the problem input").at_line(3)];
+        assert_eq!(render_game_report(&reports(&bag), &bag, &skipped, OutputFormat::Csv), "\
+game,status,fits,power,blue,blue_draw,green,green_draw,red,red_draw,details
+1,ok,false,48,6,2,2,2,4,1,
+2,ok,false,20,,,,,20,1,
+,skipped,,,,,,,,,line 3: Line was not formatted to the specification of the problem input");
+    }
+
+    #[test]
+    fn test_game_report_json() {
+        let bag = Bag::default();
+        let skipped = [Error::malformed("Missing ': '").at_line(2)];
+        let json = render_game_report(&reports(&bag)[1..], &bag, &skipped, OutputFormat::Json);
+        assert_eq!(json, "[
+  {\"game\": 2, \"status\": \"ok\", \"fits\": false, \"power\": 20, \"blue\": null, \"blue_draw\": null, \"green\": null, \"green_draw\": null, \"red\": 20, \"red_draw\": 1, \"details\": null},
+  {\"game\": null, \"status\": \"skipped\", \"fits\": null, \"power\": null, \"blue\": null, \"blue_draw\": null, \"green\": null, \"green_draw\": null, \"red\": null, \"red_draw\": null, \"details\": \"line 2: Missing ': '\"}
+]");
+    }
+
+    #[test]
+    fn test_game_report_text() {
+        let bag = Bag::default();
+        assert_eq!(render_game_report(&reports(&bag)[..1], &bag, &[], OutputFormat::Text), "\
+Game 1: needs 6 blue, 2 green, 4 red (power 48), fits 14 blue, 13 green, 12 red
+  blue from draw 2, green from draw 2, red from draw 1");
+    }
+}
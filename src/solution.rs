@@ -0,0 +1,179 @@
+use std::fmt;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+use num::{BigUint, ToPrimitive};
+use crate::error::Error;
+
+/// The answer to one part of a puzzle. Answers are what gets typed into the
+/// advent of code website, so they only need to be displayable and comparable.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Number(i64),
+    /// A number too large for `Number`.
+    Big(BigUint),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Big(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        match i64::try_from(value) {
+            Ok(number) => Answer::Number(number),
+            Err(_) => Answer::Big(BigUint::from(value)),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        match i64::try_from(value) {
+            Ok(number) => Answer::Number(number),
+            Err(_) => Answer::Big(BigUint::from(value)),
+        }
+    }
+}
+
+/// Numbers that fit are kept as `Number`, so they compare equal however they were computed.
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        match value.to_i64() {
+            Some(number) => Answer::Number(number),
+            None => Answer::Big(value),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ParseMode {
+    /// Lines that can't be parsed are skipped and recorded.
+    #[default]
+    Lenient,
+    /// The first line that can't be parsed fails the whole part.
+    Strict,
+}
+
+/// State shared with a solution while it runs, currently just the lines it had to skip.
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    pub mode: ParseMode,
+    pub skipped: Vec<Error>,
+}
+
+impl Context {
+    pub fn new(mode: ParseMode) -> Self {
+        Context { mode, skipped: Vec::new() }
+    }
+
+    /// Called when a line can't be used. Strict mode hands the error back so it can be
+    /// propagated, lenient mode records it and lets the solution carry on.
+    pub fn skip(&mut self, error: Error) -> Result<(), Error> {
+        match self.mode {
+            ParseMode::Strict => Err(error),
+            ParseMode::Lenient => {
+                self.skipped.push(error);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A single day of the calendar. The puzzle input is parsed once and then
+/// shared by both parts.
+pub trait Solution {
+    type Input;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(input: &str, context: &mut Context) -> Result<Self::Input, Error>;
+    fn part_1(input: &Self::Input, context: &mut Context) -> Result<Self::Answer1, Error>;
+    fn part_2(input: &Self::Input, context: &mut Context) -> Result<Self::Answer2, Error>;
+}
+
+fn solve_parsed<S: Solution>(parsed: &S::Input, part: u32, context: &mut Context) -> Result<Answer, Error> {
+    match part {
+        1 => S::part_1(parsed, context).map(Into::into),
+        2 => S::part_2(parsed, context).map(Into::into),
+        _ => Err(Error::UnknownPart(part)),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartResult {
+    pub part: u32,
+    pub result: Result<Answer, Error>,
+    /// Lines left out of the answer, only ever filled in lenient mode.
+    pub skipped: Vec<Error>,
+    /// The input is parsed once for all parts, so every part reports the same parse time.
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Parses the input once and solves each of the requested parts with it,
+/// returning the answers rather than printing them.
+pub fn run_parts<S: Solution>(input: &str, parts: &[u32], mode: ParseMode) -> Vec<PartResult> {
+    let mut parse_context = Context::new(mode);
+    let parse_start = Instant::now();
+    let parsed = S::parse(input, &mut parse_context);
+    let parse_time = parse_start.elapsed();
+
+    parts.iter().map(|part| {
+        // Lines skipped while parsing are left out of every part
+        let mut context = parse_context.clone();
+        let solve_start = Instant::now();
+        let result = match &parsed {
+            Ok(parsed) => solve_parsed::<S>(parsed, *part, &mut context),
+            Err(err) => Err(err.clone()),
+        };
+        let solve_time = solve_start.elapsed();
+        PartResult { part: *part, result, skipped: context.skipped, parse_time, solve_time }
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_large_numbers_become_big_answers() {
+        assert_eq!(Answer::from(42_usize), Answer::Number(42));
+        assert_eq!(Answer::from(BigUint::from(42_u8)), Answer::Number(42));
+
+        let big = Answer::from(usize::MAX);
+        assert_eq!(big, Answer::Big(BigUint::from(usize::MAX)));
+        assert_eq!(big.to_string(), usize::MAX.to_string());
+        assert_eq!(Answer::from(BigUint::from(2_u8).pow(70)).to_string(), "1180591620717411303424");
+    }
+}
@@ -1,42 +1,95 @@
+pub mod grid;
 
 pub mod file {
-    use std::fs::File;
-    use std::io;
-    use std::io::BufRead;
-    use std::path::Path;
+    use std::{env, fs, io};
+    use std::io::Read;
+    use std::path::{Path, PathBuf};
+    use crate::error::Error;
+    use crate::solution::Context;
 
+    pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+    const DEFAULT_INPUT_DIR: &str = "inputs";
 
-    pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-        where P: AsRef<Path>, {
-        let file = File::open(filename)?;
-        Ok(io::BufReader::new(file).lines())
+    /// The directory holding the puzzle inputs, which can be moved with `AOC_INPUT_DIR`.
+    pub fn input_dir() -> PathBuf {
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(DEFAULT_INPUT_DIR),
+        }
+    }
+
+
+    pub fn read_input<P>(filename: P) -> Result<String, Error> where P: AsRef<Path> {
+        fs::read_to_string(&filename).map_err(|err| Error::io(Some(filename.as_ref().to_path_buf()), err))
+    }
+
+
+    pub fn read_stdin() -> Result<String, Error> {
+        let mut input = String::new();
+        match io::stdin().read_to_string(&mut input) {
+            Ok(_) => Ok(input),
+            Err(err) => Err(Error::io(None, err)),
+        }
     }
 
 
-    pub fn aggregate_lines<P, F, R>(filename: P, line_processor: F, init: R) -> Result<R, &'static str> where P: AsRef<Path>, F: Fn(&R, &str) -> Option<R> {
+    /// Folds every line of the input into a result. Lines the processor rejects are
+    /// handed to the context, which either skips them or fails depending on the mode.
+    /// Overflow is not the line's fault, so it always fails.
+    pub fn aggregate_lines<F, R>(input: &str, context: &mut Context, line_processor: F, init: R) -> Result<R, Error> where F: Fn(&R, &str) -> Result<R, Error> {
         let mut agg = init;
-        if let Ok(lines) = read_lines(filename) {
-            for line in lines {
-                match line {
-                    Ok(code) => {
-                        if let Some(new_result) = line_processor(&agg, &code) {
-                            agg = new_result;
-                        }
-                    }
-                    Err(_) => {
-                        return Err("Error reading the file, please try again");
-                    }
+        for (line_index, line) in input.lines().enumerate() {
+            match line_processor(&agg, line) {
+                Ok(new_result) => {
+                    agg = new_result;
+                }
+                Err(err @ Error::Overflow(_)) => return Err(err),
+                Err(err) => {
+                    context.skip(err.at_line(line_index + 1))?;
                 }
             }
         }
         Ok(agg)
     }
+
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_read_input_reports_missing_path() {
+            let error = read_input("inputs/day_0_input.txt").unwrap_err();
+            match &error {
+                Error::Io { path, source } => {
+                    assert_eq!(path.as_deref(), Some(Path::new("inputs/day_0_input.txt")));
+                    assert_eq!(source.kind(), io::ErrorKind::NotFound);
+                }
+                _ => panic!("Expected an io error"),
+            }
+            assert!(error.to_string().starts_with("Could not read inputs/day_0_input.txt"));
+        }
+
+        #[test]
+        fn test_aggregate_lines_does_not_skip_overflow() {
+            let mut context = Context::default();
+            let add = |total: &i32, line: &str| -> Result<i32, Error> {
+                let value = line.parse::<i32>().map_err(|err| Error::parse(line, err))?;
+                total.checked_add(value).ok_or(Error::overflow("Too large"))
+            };
+            assert_eq!(aggregate_lines("1\nx\n2", &mut context, add, 0), Ok(3));
+            assert_eq!(context.skipped.len(), 1);
+            assert_eq!(aggregate_lines("1\nx\n2", &mut context, add, i32::MAX - 2), Err(Error::overflow("Too large")));
+        }
+    }
 }
 
 pub mod output {
     use std::fmt::Display;
+    use std::time::Duration;
+    use crate::error::Error;
 
-    pub fn print_solution<T:Display>(day: i32, part: i32, potential_result: Result<T, &str>)  {
+    pub fn print_solution<T:Display>(day: i32, part: i32, potential_result: &Result<T, Error>)  {
         match potential_result {
             Ok(result) => {
                 let message = format!("Solution to day {} part {} is {}", day, part, result);
@@ -44,8 +97,12 @@ pub mod output {
 
             }
             Err(msg) => {
-                println!("{}", msg)
+                println!("Could not solve day {} part {}: {}", day, part, msg)
             }
         }
     }
+
+    pub fn format_duration(duration: Duration) -> String {
+        format!("{:.2?}", duration)
+    }
 }
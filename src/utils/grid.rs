@@ -0,0 +1,203 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::error::Error;
+
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const OFFSETS_8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+/// A rectangular grid stored row by row. Positions are `(row, col)` pairs starting at 0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from rows of cells, or returns `None` if the rows are not all the same width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let height = rows.len();
+        Some(Grid { width, height, cells: rows.into_iter().flatten().collect() })
+    }
+
+    /// Parses one row per line, converting each character with `cell`. Errors carry the
+    /// line and column of the offending character.
+    pub fn parse<F>(input: &str, cell: F) -> Result<Grid<T>, Error> where F: Fn(char) -> Result<T, Error> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (line_index, line) in input.lines().enumerate() {
+            let row = line.char_indices()
+                .map(|(offset, c)| cell(c).map_err(|err| err.at_token(line, &line[offset..])))
+                .collect::<Result<Vec<T>, Error>>()
+                .map_err(|err| err.at_line(line_index + 1))?;
+
+            if let Some(first_row) = rows.first() {
+                if row.len() != first_row.len() {
+                    let message = format!("Expected a row {} characters wide, found {}", first_row.len(), row.len());
+                    return Err(Error::malformed(message).at_line(line_index + 1));
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows).expect("rows were checked to be the same width"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, row: usize, col: usize) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if !self.in_bounds(row, col) {
+            return None;
+        }
+        self.cells.get(row * self.width + col)
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        self.rows().nth(row)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        let width = self.width;
+        (0..self.height).map(move |row| &self.cells[row * width..(row + 1) * width])
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let height = if col < self.width { self.height } else { 0 };
+        // `step_by` needs a non-zero step, and a grid with no columns yields nothing anyway
+        self.cells.iter().skip(col).step_by(self.width.max(1)).take(height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    fn offset(&self, (row, col): (usize, usize), (row_offset, col_offset): (isize, isize)) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(row_offset)?;
+        let col = col.checked_add_signed(col_offset)?;
+        self.in_bounds(row, col).then_some((row, col))
+    }
+
+    /// The positions directly above, left, right and below, skipping any outside the grid.
+    pub fn neighbors_4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_4.iter().filter_map(move |offset| self.offset((row, col), *offset))
+    }
+
+    /// The positions around `(row, col)` including diagonals, row by row, skipping any
+    /// outside the grid.
+    pub fn neighbors_8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_8.iter().filter_map(move |offset| self.offset((row, col), *offset))
+    }
+
+    /// The positions at most `radius` rows and columns away from `(row, col)`, row by row,
+    /// skipping `(row, col)` itself and any outside the grid.
+    pub fn neighbors_within(&self, row: usize, col: usize, radius: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let rows = row.saturating_sub(radius)..row.saturating_add(radius).saturating_add(1).min(self.height);
+        let cols = col.saturating_sub(radius)..col.saturating_add(radius).saturating_add(1).min(self.width);
+        rows.flat_map(move |r| cols.clone().map(move |c| (r, c)))
+            .filter(move |position| *position != (row, col))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Grid<char>, Error> {
+        Grid::parse(input, Ok)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (row_index, row) in self.rows().enumerate() {
+            if row_index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef\nghi".parse().unwrap()
+    }
+
+    fn cells(grid: &Grid<char>, positions: impl Iterator<Item = (usize, usize)>) -> String {
+        positions.map(|(row, col)| grid.get(row, col).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_parse_and_get() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.get(1, 2), Some(&'f'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.to_string(), "abc\ndef\nghi");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = "abc\nde\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(error.to_string(), "line 2: Expected a row 3 characters wide, found 2");
+
+        let error = Grid::parse("12\n3x", |c| c.to_string().parse::<u8>().map_err(|err| Error::parse(&c.to_string(), err))).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: could not parse 'x' as a number (invalid digit found in string)");
+
+        let empty: Grid<char> = "".parse().unwrap();
+        assert_eq!((empty.width(), empty.height(), empty.rows().count()), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def", "ghi"]);
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(3), None);
+
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, vec!["adg", "beh", "cfi"]);
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        assert_eq!(cells(&grid, grid.neighbors_4(1, 1)), "bdfh");
+        assert_eq!(cells(&grid, grid.neighbors_8(1, 1)), "abcdfghi");
+        assert_eq!(cells(&grid, grid.neighbors_4(0, 0)), "bd");
+        assert_eq!(cells(&grid, grid.neighbors_8(0, 0)), "bde");
+        assert_eq!(cells(&grid, grid.neighbors_8(2, 2)), "efh");
+        assert_eq!(cells(&grid, grid.positions()), "abcdefghi");
+
+        assert_eq!(cells(&grid, grid.neighbors_within(1, 1, 1)), "abcdfghi");
+        assert_eq!(cells(&grid, grid.neighbors_within(0, 0, 1)), "bde");
+        assert_eq!(cells(&grid, grid.neighbors_within(0, 0, 2)), "bcdefghi");
+        assert_eq!(cells(&grid, grid.neighbors_within(2, 1, 0)), "");
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use crate::error::Error;
+use crate::format::{render, OutputFormat, Row};
+use crate::runner::RunResult;
+use crate::utils::file::read_input;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The accepted answers for each day and part, read from a small subset of toml:
+///
+/// ```toml
+/// [day_1]
+/// part_1 = 55477
+/// part_2 = "some text answer"
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct KnownAnswers {
+    answers: HashMap<(u32, u32), String>,
+}
+
+fn parse_number_after(prefix: &str, token: &str) -> Option<u32> {
+    token.strip_prefix(prefix).and_then(|number| number.parse::<u32>().ok())
+}
+
+fn parse_value(value: &str) -> Option<String> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        return quoted.strip_suffix('"').map(String::from);
+    }
+    if !value.is_empty() && value.chars().all(|c| c.is_ascii_digit() || c == '-') {
+        return Some(value.to_string());
+    }
+    None
+}
+
+impl KnownAnswers {
+    pub fn parse(text: &str) -> Result<KnownAnswers, Error> {
+        let mut answers = HashMap::new();
+        let mut current_day = None;
+
+        for (line_index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(section) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+                match parse_number_after("day_", section.trim()) {
+                    Some(day) => current_day = Some(day),
+                    None => return Err(Error::malformed(format!("Expected a [day_N] section, found [{}]", section)).at_line(line_index + 1)),
+                }
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(Error::malformed("Expected 'part_N = answer'").at_line(line_index + 1));
+            };
+            let (Some(day), Some(part)) = (current_day, parse_number_after("part_", key.trim())) else {
+                return Err(Error::malformed(format!("Expected 'part_N' inside a [day_N] section, found '{}'", key.trim())).at_line(line_index + 1));
+            };
+            match parse_value(value.trim()) {
+                Some(answer) => {
+                    answers.insert((day, part), answer);
+                }
+                None => return Err(Error::malformed(format!("Answers must be integers or quoted strings, found '{}'", value.trim())).at_line(line_index + 1)),
+            }
+        }
+        Ok(KnownAnswers { answers })
+    }
+
+    /// Loads the answers file, treating a missing file as having no answers recorded yet.
+    pub fn load(path: &Path) -> Result<KnownAnswers, Error> {
+        if !path.exists() {
+            return Ok(KnownAnswers::default());
+        }
+        KnownAnswers::parse(&read_input(path)?)
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|answer| answer.as_str())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    /// The solution ran but no longer gives the accepted answer.
+    Regression { expected: String },
+    /// The solution returned an error.
+    Fail,
+    /// There is no accepted answer to compare against.
+    Unverified,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Regression { .. } => write!(f, "regression"),
+            Status::Fail => write!(f, "fail"),
+            Status::Unverified => write!(f, "unverified"),
+        }
+    }
+}
+
+pub fn check(run: &RunResult, known_answers: &KnownAnswers) -> Status {
+    match (&run.result, known_answers.get(run.day, run.part)) {
+        (Err(_), _) => Status::Fail,
+        (Ok(_), None) => Status::Unverified,
+        (Ok(answer), Some(expected)) if answer.to_string() == expected => Status::Pass,
+        (Ok(_), Some(expected)) => Status::Regression { expected: expected.to_string() },
+    }
+}
+
+/// Prints the status of every result and returns false if anything failed or regressed.
+pub fn report(results: &[RunResult], known_answers: &KnownAnswers, format: OutputFormat) -> bool {
+    let (mut passed, mut regressed, mut failed, mut unverified) = (0, 0, 0, 0);
+    let mut rows: Vec<Row> = Vec::new();
+
+    for run in results {
+        let status = check(run, known_answers);
+        let details = match &status {
+            Status::Regression { expected } => Some(format!("expected {}", expected)),
+            _ => None,
+        };
+        rows.push(run.to_row(status.to_string(), details));
+
+        if format == OutputFormat::Text {
+            match (&status, &run.result) {
+                (Status::Regression { expected }, Ok(answer)) => {
+                    println!("Day {} part {}: {}, expected {} but got {}", run.day, run.part, status, expected, answer);
+                }
+                (_, Ok(answer)) => println!("Day {} part {}: {} ({})", run.day, run.part, status, answer),
+                (_, Err(err)) => println!("Day {} part {}: {} ({})", run.day, run.part, status, err),
+            }
+        }
+
+        match status {
+            Status::Pass => passed += 1,
+            Status::Regression { .. } => regressed += 1,
+            Status::Fail => failed += 1,
+            Status::Unverified => unverified += 1,
+        }
+    }
+
+    match render(format, &rows) {
+        Some(output) => println!("{}", output),
+        None => println!("{} passed, {} regressed, {} failed, {} unverified", passed, regressed, failed, unverified),
+    }
+    regressed == 0 && failed == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use crate::solution::Answer;
+
+    fn run_result(day: u32, part: u32, result: Result<Answer, Error>) -> RunResult {
+        RunResult { day, part, result, skipped: Vec::new(), parse_time: Duration::ZERO, solve_time: Duration::ZERO }
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let known_answers = KnownAnswers::parse("# accepted answers\n[day_1]\npart_1 = 142\npart_2 = \"abc\" # text\n\n[day_3]\npart_2 = -5\n").unwrap();
+        assert_eq!(known_answers.get(1, 1), Some("142"));
+        assert_eq!(known_answers.get(1, 2), Some("abc"));
+        assert_eq!(known_answers.get(3, 2), Some("-5"));
+        assert_eq!(known_answers.get(3, 1), None);
+    }
+
+    #[test]
+    fn test_parse_answers_errors() {
+        assert_eq!(KnownAnswers::parse("part_1 = 3"), Err(Error::malformed("Expected 'part_N' inside a [day_N] section, found 'part_1'").at_line(1)));
+        assert_eq!(KnownAnswers::parse("[day_1]\npart_1 = abc"), Err(Error::malformed("Answers must be integers or quoted strings, found 'abc'").at_line(2)));
+        assert_eq!(KnownAnswers::parse("[day one]"), Err(Error::malformed("Expected a [day_N] section, found [day one]").at_line(1)));
+    }
+
+    #[test]
+    fn test_check() {
+        let known_answers = KnownAnswers::parse("[day_1]\npart_1 = 142\npart_2 = 281").unwrap();
+
+        assert_eq!(check(&run_result(1, 1, Ok(Answer::Number(142))), &known_answers), Status::Pass);
+        assert_eq!(check(&run_result(1, 2, Ok(Answer::Number(280))), &known_answers), Status::Regression { expected: String::from("281") });
+        assert_eq!(check(&run_result(1, 2, Err(Error::UnknownPart(2))), &known_answers), Status::Fail);
+        assert_eq!(check(&run_result(2, 1, Ok(Answer::Number(8))), &known_answers), Status::Unverified);
+    }
+}